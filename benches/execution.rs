@@ -0,0 +1,275 @@
+//! Stylus and EVM running equivalent workloads, side by side. Each workload is benchmarked
+//! twice: for wall-clock time, and with [`Gas`] as the measurement, so criterion records the
+//! gas it uses and reports changes against the previous run the same way it does for time.
+
+use alloy_sol_types::{sol, SolCall};
+use criterion::{
+    criterion_group, criterion_main,
+    measurement::{Measurement, ValueFormatter},
+    Criterion, Throughput,
+};
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{hex, keccak256, Address, ExecutionResult, TxEnv, TxKind, U256};
+
+use arbos_revm_tests::multicall::Multicaller;
+use arbos_revm_tests::{
+    deploy_solidity, deploy_wasm, evm_contract_init_code, setup_simple_test,
+    wasm_contract_init_code, DEPLOYER, EVM_CHILD_RUNTIME,
+};
+
+const BENCH_PROGRAM_BYTECODE: &[u8] = include_bytes!("../tests/assets/bench_program.wasm");
+const BENCH_PROGRAM_EVM_BYTECODE: &str = include_str!("../tests/assets/BenchProgram.bin");
+const MULTICALL_BYTECODE: &[u8] = include_bytes!("../tests/assets/multicall.wasm");
+const MULTICALL_EVM_BYTECODE: &str = include_str!("../tests/assets/Multicaller.bin");
+const CREATE_PROGRAM_BYTECODE: &[u8] = include_bytes!("../tests/assets/create_program.wasm");
+const CREATE_TEST_EVM_BYTECODE: &str = include_str!("../tests/assets/CreateTest.bin");
+const EMIT_LOG_PROGRAM_BYTECODE: &[u8] = include_bytes!("../tests/assets/emit_log.wasm");
+
+const STORAGE_SLOTS: u64 = 100;
+const KECCAK_ROUNDS: u64 = 1000;
+const LOGS: u64 = 100;
+const FAN_OUT: usize = 16;
+
+sol!("tests/assets/abi/create_program.sol");
+sol!("tests/assets/abi/bench_program.sol");
+
+#[derive(Clone, Copy, Debug)]
+enum Vm {
+    Stylus,
+    Evm,
+}
+
+impl Vm {
+    const ALL: [Vm; 2] = [Vm::Stylus, Vm::Evm];
+
+    fn name(self) -> &'static str {
+        match self {
+            Vm::Stylus => "stylus",
+            Vm::Evm => "evm",
+        }
+    }
+}
+
+/// One VM's side of the comparison.
+struct Contracts {
+    bench: Address,
+    multicall: Address,
+    factory: Address,
+    child_init_code: Vec<u8>,
+}
+
+struct Bench {
+    db: CacheDB<EmptyDB>,
+    stylus: Contracts,
+    evm: Contracts,
+}
+
+impl Bench {
+    fn new() -> Self {
+        let mut db = CacheDB::new(EmptyDB::new());
+        setup_simple_test(&mut db);
+
+        let stylus = Contracts {
+            bench: deploy_wasm(&mut db, BENCH_PROGRAM_BYTECODE.to_vec(), DEPLOYER),
+            multicall: deploy_wasm(&mut db, MULTICALL_BYTECODE.to_vec(), DEPLOYER),
+            factory: deploy_wasm(&mut db, CREATE_PROGRAM_BYTECODE.to_vec(), DEPLOYER),
+            child_init_code: wasm_contract_init_code(EMIT_LOG_PROGRAM_BYTECODE.to_vec()),
+        };
+        let evm = Contracts {
+            bench: deploy_solidity(&mut db, hex::decode(BENCH_PROGRAM_EVM_BYTECODE).unwrap(), DEPLOYER),
+            multicall: deploy_solidity(&mut db, hex::decode(MULTICALL_EVM_BYTECODE).unwrap(), DEPLOYER),
+            factory: deploy_solidity(&mut db, hex::decode(CREATE_TEST_EVM_BYTECODE).unwrap(), DEPLOYER),
+            child_init_code: evm_contract_init_code(EVM_CHILD_RUNTIME.to_vec()),
+        };
+
+        Self { db, stylus, evm }
+    }
+
+    fn contracts(&self, vm: Vm) -> &Contracts {
+        match vm {
+            Vm::Stylus => &self.stylus,
+            Vm::Evm => &self.evm,
+        }
+    }
+
+    /// Runs the call without committing it, so every iteration starts from the same state.
+    fn transact(&mut self, to: Address, data: &[u8]) -> ExecutionResult {
+        let mut evm = revm::Evm::builder()
+            .with_db(&mut self.db)
+            .modify_tx_env(|tx: &mut TxEnv| {
+                tx.caller = DEPLOYER;
+                tx.transact_to = TxKind::Call(to);
+                tx.data = data.to_vec().into();
+                tx.gas_limit = 1_000_000_000;
+            })
+            .build();
+
+        evm.transact().unwrap().result
+    }
+}
+
+/// A workload as a call to make against either VM's contracts.
+type Workload = fn(&Contracts) -> (Address, Vec<u8>);
+
+fn storage(contracts: &Contracts) -> (Address, Vec<u8>) {
+    let calldata = IBenchProgram::storageLoopCall {
+        count: STORAGE_SLOTS,
+    };
+    (contracts.bench, calldata.abi_encode())
+}
+
+fn keccak(contracts: &Contracts) -> (Address, Vec<u8>) {
+    let calldata = IBenchProgram::keccakLoopCall {
+        seed: keccak256("seed"),
+        count: KECCAK_ROUNDS,
+    };
+    (contracts.bench, calldata.abi_encode())
+}
+
+fn logs(contracts: &Contracts) -> (Address, Vec<u8>) {
+    let calldata = IBenchProgram::emitLogsCall { count: LOGS };
+    (contracts.bench, calldata.abi_encode())
+}
+
+fn fan_out(contracts: &Contracts) -> (Address, Vec<u8>) {
+    let call = Multicaller::Call {
+        callType: Multicaller::CallType::CALL,
+        target: contracts.bench,
+        data: IBenchProgram::keccakLoopCall {
+            seed: keccak256("seed"),
+            count: 1,
+        }
+        .abi_encode()
+        .into(),
+        value: U256::ZERO,
+        gas_limit: U256::ZERO,
+    };
+    let calldata = Multicaller::multicallCall {
+        calls: vec![call; FAN_OUT],
+    };
+    (contracts.multicall, calldata.abi_encode())
+}
+
+fn create(contracts: &Contracts) -> (Address, Vec<u8>) {
+    let calldata = ICreateProgram::createCall {
+        init_code: contracts.child_init_code.clone().into(),
+        endowment: U256::ZERO,
+    };
+    (contracts.factory, calldata.abi_encode())
+}
+
+const WORKLOADS: &[(&str, Workload)] = &[
+    ("storage", storage),
+    ("keccak", keccak),
+    ("logs", logs),
+    ("multicall_fan_out", fan_out),
+    ("create", create),
+];
+
+/// Gas used by the measured calls. Execution is deterministic, so every sample is the same
+/// and criterion flags any change from the last run.
+struct Gas;
+
+impl Measurement for Gas {
+    type Intermediate = ();
+    type Value = u64;
+
+    fn start(&self) -> Self::Intermediate {}
+
+    // Gas comes from the call results through `iter_custom`, never from timing a closure.
+    fn end(&self, _: Self::Intermediate) -> Self::Value {
+        0
+    }
+
+    fn add(&self, v1: &Self::Value, v2: &Self::Value) -> Self::Value {
+        v1 + v2
+    }
+
+    fn zero(&self) -> Self::Value {
+        0
+    }
+
+    fn to_f64(&self, value: &Self::Value) -> f64 {
+        *value as f64
+    }
+
+    fn formatter(&self) -> &dyn ValueFormatter {
+        &GasFormatter
+    }
+}
+
+struct GasFormatter;
+
+impl ValueFormatter for GasFormatter {
+    fn scale_values(&self, _typical_value: f64, _values: &mut [f64]) -> &'static str {
+        "gas"
+    }
+
+    fn scale_throughputs(
+        &self,
+        _typical_value: f64,
+        throughput: &Throughput,
+        values: &mut [f64],
+    ) -> &'static str {
+        let (count, unit) = match *throughput {
+            Throughput::Bytes(bytes) | Throughput::BytesDecimal(bytes) => (bytes, "gas/byte"),
+            Throughput::Elements(elements) => (elements, "gas/element"),
+        };
+        for value in values {
+            *value /= count as f64;
+        }
+        unit
+    }
+
+    fn scale_for_machines(&self, _values: &mut [f64]) -> &'static str {
+        "gas"
+    }
+}
+
+fn execution(c: &mut Criterion) {
+    let mut bench = Bench::new();
+
+    for (name, workload) in WORKLOADS {
+        let mut group = c.benchmark_group(*name);
+
+        for vm in Vm::ALL {
+            let (to, calldata) = workload(bench.contracts(vm));
+
+            let result = bench.transact(to, &calldata);
+            assert!(result.is_success(), "{}/{}: {:?}", name, vm.name(), result);
+
+            group.bench_function(vm.name(), |b| b.iter(|| bench.transact(to, &calldata)));
+        }
+
+        group.finish();
+    }
+}
+
+fn gas(c: &mut Criterion<Gas>) {
+    let mut bench = Bench::new();
+
+    for (name, workload) in WORKLOADS {
+        let mut group = c.benchmark_group(format!("{}_gas", name));
+
+        for vm in Vm::ALL {
+            let (to, calldata) = workload(bench.contracts(vm));
+
+            group.bench_function(vm.name(), |b| {
+                b.iter_custom(|iters| {
+                    (0..iters).map(|_| bench.transact(to, &calldata).gas_used()).sum()
+                })
+            });
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, execution);
+criterion_group! {
+    name = gas_benches;
+    // Identical samples leave nothing to plot.
+    config = Criterion::default().with_measurement(Gas).without_plots().sample_size(10);
+    targets = gas
+}
+criterion_main!(benches, gas_benches);
@@ -1,9 +1,14 @@
 #!/bin/bash
 
 rm -rf tests/assets
-mkdir -p tests/assets
+mkdir -p tests/assets/abi
 
-solc solidity-contracts/Multicaller.sol --bin --output-dir tests/assets
+solc solidity-contracts/Multicaller.sol --bin --abi --output-dir tests/assets
+solc solidity-contracts/HostioProbe.sol --bin --abi --output-dir tests/assets
+solc solidity-contracts/CreateTest.sol --bin --abi --output-dir tests/assets
+solc solidity-contracts/Recursive.sol --bin --abi --output-dir tests/assets
+solc solidity-contracts/BenchProgram.sol --bin --abi --output-dir tests/assets
+solc solidity-contracts/EmitLog.sol --bin --abi --output-dir tests/assets
 
 # for each in directory
 $(
@@ -13,6 +18,12 @@ $(
             cd $dir
             cargo build --release --lib
             cp target/wasm32-unknown-unknown/release/$(echo $dir | tr '-' '_').wasm ../../tests/assets
+
+            # Programs with an export-abi feature also get their Solidity interface exported
+            if grep -q '^export-abi' Cargo.toml; then
+                cargo run --quiet --features export-abi --target $(rustc -vV | sed -n 's/^host: //p') \
+                    > ../../tests/assets/abi/$(echo $dir | tr '-' '_').sol
+            fi
         )
     done
 )
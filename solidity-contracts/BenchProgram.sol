@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.12;
+
+// solc BenchProgram.sol --bin
+
+// EVM counterpart of stylus-contracts/bench-program.
+contract BenchProgram {
+    function storageLoop(uint64 count) external returns (uint256 sum) {
+        for (uint256 i = 0; i < count; i++) {
+            assembly {
+                sstore(i, add(i, 1))
+            }
+        }
+        for (uint256 i = 0; i < count; i++) {
+            assembly {
+                sum := add(sum, sload(i))
+            }
+        }
+    }
+
+    function keccakLoop(bytes32 seed, uint64 count) external pure returns (bytes32 hash) {
+        hash = seed;
+        for (uint256 i = 0; i < count; i++) {
+            hash = keccak256(abi.encodePacked(hash));
+        }
+    }
+
+    function emitLogs(uint64 count) external {
+        for (uint256 i = 0; i < count; i++) {
+            assembly {
+                log1(0, 0, i)
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.12;
+
+// solc CreateTest.sol --bin
+
+// EVM counterpart of stylus-contracts/create-program.
+contract CreateTest {
+    function create(bytes memory init_code, uint256 endowment) external returns (address deployed) {
+        assembly {
+            deployed := create(endowment, add(init_code, 0x20), mload(init_code))
+        }
+        bubbleFailure(deployed);
+    }
+
+    function create2(bytes memory init_code, bytes32 salt, uint256 endowment) external returns (address deployed) {
+        assembly {
+            deployed := create2(endowment, add(init_code, 0x20), mload(init_code), salt)
+        }
+        bubbleFailure(deployed);
+    }
+
+    // like create, but returns the failure instead of reverting so the factory's state persists
+    function tryCreate(bytes memory init_code, uint256 endowment) external returns (address deployed, bytes memory revert_data) {
+        assembly {
+            deployed := create(endowment, add(init_code, 0x20), mload(init_code))
+        }
+        revert_data = failureData(deployed);
+    }
+
+    function tryCreate2(bytes memory init_code, bytes32 salt, uint256 endowment) external returns (address deployed, bytes memory revert_data) {
+        assembly {
+            deployed := create2(endowment, add(init_code, 0x20), mload(init_code), salt)
+        }
+        revert_data = failureData(deployed);
+    }
+
+    function failureData(address deployed) private pure returns (bytes memory data) {
+        if (deployed == address(0)) {
+            data = new bytes(returndatasize());
+            assembly {
+                returndatacopy(add(data, 0x20), 0, returndatasize())
+            }
+        }
+    }
+
+    // revert with the child's revert data, as the Stylus factory does
+    function bubbleFailure(address deployed) private pure {
+        if (deployed == address(0)) {
+            assembly {
+                returndatacopy(0, 0, returndatasize())
+                revert(0, returndatasize())
+            }
+        }
+    }
+}
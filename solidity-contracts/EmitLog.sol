@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.12;
+
+// solc EmitLog.sol --bin
+
+// EVM counterpart of stylus-contracts/emit-log.
+contract EmitLog {
+    function emitLog(bytes32[] memory topics, bytes memory data) public {
+        require(topics.length <= 4, "too many topics");
+        assembly {
+            let ptr := add(data, 0x20)
+            let len := mload(data)
+            let t := add(topics, 0x20)
+            switch mload(topics)
+            case 0 { log0(ptr, len) }
+            case 1 { log1(ptr, len, mload(t)) }
+            case 2 { log2(ptr, len, mload(t), mload(add(t, 0x20))) }
+            case 3 { log3(ptr, len, mload(t), mload(add(t, 0x20)), mload(add(t, 0x40))) }
+            case 4 { log4(ptr, len, mload(t), mload(add(t, 0x20)), mload(add(t, 0x40)), mload(add(t, 0x60))) }
+        }
+    }
+
+    // Splits buffer into topics and data the way the Stylus emit_log hostio does.
+    function emitRawLog(uint64 topics, bytes calldata buffer) external {
+        require(buffer.length >= topics * 32, "bad topic data");
+        bytes32[] memory parsed = new bytes32[](topics);
+        for (uint256 i = 0; i < topics; i++) {
+            parsed[i] = bytes32(buffer[i * 32:(i + 1) * 32]);
+        }
+        emitLog(parsed, buffer[topics * 32:]);
+    }
+
+    function emitThenRevert(bytes32[] memory topics, bytes memory data) external {
+        emitLog(topics, data);
+        revert();
+    }
+}
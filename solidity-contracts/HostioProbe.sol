@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.12;
+
+// solc HostioProbe.sol --bin
+
+// EVM counterpart of stylus-contracts/hostio-probe.
+contract HostioProbe {
+    function context() external payable returns (address, address, uint256, uint256) {
+        return (msg.sender, tx.origin, msg.value, tx.gasprice);
+    }
+
+    function contractAddress() external view returns (address) {
+        return address(this);
+    }
+
+    function selfBalance() external view returns (uint256) {
+        return address(this).balance;
+    }
+
+    // Each pair is the gas taken by two runs of the same opcode, like the Stylus probe's
+    // hostio pairs.
+
+    function storageAccessCosts(bytes32 slot) external view returns (uint64, uint64) {
+        uint256 start = gasleft();
+        assembly { pop(sload(slot)) }
+        uint256 first = gasleft();
+        assembly { pop(sload(slot)) }
+        uint256 second = gasleft();
+        return (uint64(start - first), uint64(first - second));
+    }
+
+    function balanceAccessCosts(address account) external view returns (uint64, uint64) {
+        uint256 start = gasleft();
+        assembly { pop(balance(account)) }
+        uint256 first = gasleft();
+        assembly { pop(balance(account)) }
+        uint256 second = gasleft();
+        return (uint64(start - first), uint64(first - second));
+    }
+
+    function codeAccessCosts(address account) external view returns (uint64, uint64) {
+        uint256 start = gasleft();
+        assembly { extcodecopy(account, 0, 0, 0) }
+        uint256 first = gasleft();
+        assembly { extcodecopy(account, 0, 0, 0) }
+        uint256 second = gasleft();
+        return (uint64(start - first), uint64(first - second));
+    }
+
+    function callAccessCosts(address target) external returns (uint64, uint64) {
+        uint256 start = gasleft();
+        assembly { pop(call(gas(), target, 0, 0, 0, 0, 0)) }
+        uint256 first = gasleft();
+        assembly { pop(call(gas(), target, 0, 0, 0, 0, 0)) }
+        uint256 second = gasleft();
+        return (uint64(start - first), uint64(first - second));
+    }
+
+    function returnDataAfterCall(address target, bytes memory data, uint64 gasLimit, uint64 offset, uint64 size)
+        external
+        returns (bool success, uint64 length, bytes memory copied)
+    {
+        assembly {
+            let g := gasLimit
+            if iszero(g) { g := gas() }
+            success := call(g, target, 0, add(data, 0x20), mload(data), 0, 0)
+        }
+        (length, copied) = returnData(offset, size);
+    }
+
+    function returnDataAfterCreate(bytes memory initCode, uint64 offset, uint64 size)
+        external
+        returns (address deployed, uint64 length, bytes memory copied)
+    {
+        assembly {
+            deployed := create(0, add(initCode, 0x20), mload(initCode))
+        }
+        (length, copied) = returnData(offset, size);
+    }
+
+    // RETURNDATACOPY halts on reads past the end, where the Stylus hostio truncates.
+    function returnData(uint64 offset, uint64 size) private view returns (uint64 length, bytes memory copied) {
+        copied = new bytes(size);
+        assembly {
+            length := returndatasize()
+            returndatacopy(add(copied, 0x20), offset, size)
+        }
+    }
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.12;
+
+// solc Recursive.sol --bin
+
+// EVM counterpart of stylus-contracts/recursive.
+contract Recursive {
+    function recurse(address other, uint256 depth) external returns (uint256) {
+        if (depth == 0) {
+            return 0;
+        }
+        return Recursive(other).recurse(address(this), depth - 1) + 1;
+    }
+
+    function recurseInternal(uint64 depth) external pure returns (uint64) {
+        return descend(depth);
+    }
+
+    function tryCall(address target, bytes calldata data) external returns (bool, bytes memory) {
+        return target.call(data);
+    }
+
+    function descend(uint64 depth) private pure returns (uint64) {
+        if (depth == 0) {
+            return 0;
+        }
+        return descend(depth - 1) + 1;
+    }
+}
@@ -0,0 +1,334 @@
+//! Deploys a Stylus program or EVM contract and calls it once through arbos-revm, for
+//! poking at a fixture without writing a test:
+//!
+//! ```text
+//! cargo run --bin stylus-run -- tests/assets/test_program.wasm \
+//!     --sig "getStorage(bytes32) returns (bytes32)" --args 0x01 --trace
+//! ```
+//!
+//! Deployment goes through the same helpers as the tests. Deploying a program only stores
+//! its code; Nitro activates it on the first call, so activation errors show up in the
+//! call's result rather than as a failed deployment.
+//!
+//! The report covers the result, a gas breakdown, logs, the call's state diff and, with
+//! `--trace`, every call frame; `--json` prints it as JSON instead of text.
+
+use std::{fs, path::Path, path::PathBuf, process::ExitCode};
+
+use alloy_sol_types::decode_revert_reason;
+use clap::Parser;
+use revm::{
+    db::CacheDB,
+    inspector_handle_register,
+    primitives::{
+        hex, AccountInfo, Address, BlockEnv, Bytes, ExecutionResult, SpecId::LATEST, TxEnv,
+        TxKind, KECCAK_EMPTY, U256,
+    },
+    DatabaseCommit, DatabaseRef,
+};
+use serde::Serialize;
+
+use arbos_revm_tests::{
+    calldata, configure, evm_contract_init_code,
+    gas::GasBreakdown,
+    replay::ReplayLog,
+    state_diff::{diff_transaction, StateDiff},
+    state_dump::StateDump,
+    trace::{CallTracer, Frame},
+    try_deploy_solidity, try_deploy_wasm, DEPLOYER,
+};
+
+#[derive(Debug, Parser)]
+#[command(about = "Deploy and call a Stylus program or EVM contract through arbos-revm")]
+struct Args {
+    /// WASM or WAT program, or hex EVM bytecode (init code unless --runtime)
+    #[arg(required_unless_present = "to")]
+    code: Option<PathBuf>,
+
+    /// Call an account from the prestate instead of deploying code
+    #[arg(long, conflicts_with = "code")]
+    to: Option<Address>,
+
+    /// Deploy EVM bytecode as runtime code, without running a constructor
+    #[arg(long)]
+    runtime: bool,
+
+    /// Calldata as hex
+    #[arg(long, conflicts_with = "sig")]
+    calldata: Option<String>,
+
+    /// Function to call, e.g. "setStorage(bytes32,bytes32)". Add "returns (...)" to decode
+    /// the output
+    #[arg(long)]
+    sig: Option<String>,
+
+    /// Arguments for --sig
+    #[arg(long, num_args = 1.., allow_hyphen_values = true, requires = "sig")]
+    args: Vec<String>,
+
+    /// State to start from: a genesis alloc, anvil dump or bare account map
+    #[arg(long)]
+    prestate: Option<PathBuf>,
+
+    /// Deploys and sends the call; funded with 1 ETH if it has no account
+    #[arg(long, default_value_t = DEPLOYER)]
+    caller: Address,
+
+    #[arg(long, default_value_t = U256::ZERO)]
+    value: U256,
+
+    #[arg(long, default_value_t = 1_000_000_000)]
+    gas_limit: u64,
+
+    #[arg(long, default_value_t = U256::ZERO)]
+    gas_price: U256,
+
+    #[arg(long)]
+    basefee: Option<U256>,
+
+    #[arg(long)]
+    block_number: Option<u64>,
+
+    #[arg(long)]
+    timestamp: Option<u64>,
+
+    #[arg(long)]
+    chain_id: Option<u64>,
+
+    /// Include every call frame in the report
+    #[arg(long)]
+    trace: bool,
+
+    /// Print the report as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Report {
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    halt_reason: Option<String>,
+    target: Address,
+    deployed: bool,
+    output: Bytes,
+    /// The output decoded with `--sig`'s return types, or a revert reason.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    decoded: Option<Vec<String>>,
+    gas: GasBreakdown,
+    logs: Vec<ReplayLog>,
+    state_diff: StateDiff,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trace: Option<Vec<Frame>>,
+}
+
+fn deploy(
+    db: &mut CacheDB<StateDump>,
+    path: &Path,
+    runtime: bool,
+    deployer: Address,
+) -> Result<Address, String> {
+    let bytes = fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    let (deployed, result) = if bytes.starts_with(b"\0asm") {
+        try_deploy_wasm(db, bytes, deployer)
+    } else if path.extension().is_some_and(|ext| ext == "wat") {
+        let wasm = wat::parse_bytes(&bytes).map_err(|e| format!("Invalid WAT: {}", e))?;
+        try_deploy_wasm(db, wasm.into_owned(), deployer)
+    } else {
+        let code = hex::decode(String::from_utf8_lossy(&bytes).trim())
+            .map_err(|e| format!("{} is neither WASM nor hex bytecode: {}", path.display(), e))?;
+        let init_code = if runtime { evm_contract_init_code(code) } else { code };
+        try_deploy_solidity(db, init_code, deployer)
+    };
+
+    match result {
+        None => return Err(format!("Deploying {} was rejected", path.display())),
+        Some(result) if !result.is_success() => {
+            return Err(format!("Deploying {} failed: {:?}", path.display(), result));
+        }
+        Some(_) => {}
+    }
+
+    // A constructor can succeed without returning any code.
+    let code_hash = db.basic_ref(deployed).unwrap().map(|info| info.code_hash);
+    if code_hash.unwrap_or(KECCAK_EMPTY) == KECCAK_EMPTY {
+        return Err(format!("Deploying {} left no code", path.display()));
+    }
+    Ok(deployed)
+}
+
+fn run(args: &Args) -> Result<Report, String> {
+    let dump = match &args.prestate {
+        Some(path) => StateDump::load(path)?,
+        None => StateDump::default(),
+    };
+    let mut db = CacheDB::new(dump);
+
+    if db.basic_ref(args.caller).unwrap().is_none() {
+        let mut info = AccountInfo::default();
+        info.balance = U256::from(1e18);
+        db.insert_account_info(args.caller, info);
+    }
+
+    let (target, deployed) = match (&args.code, args.to) {
+        (Some(path), _) => (deploy(&mut db, path, args.runtime, args.caller)?, true),
+        (None, Some(to)) => (to, false),
+        (None, None) => unreachable!("clap requires code or --to"),
+    };
+
+    let (data, function) =
+        calldata::encode(args.calldata.as_deref(), args.sig.as_deref(), &args.args)?;
+
+    let mut evm = revm::Evm::builder()
+        .with_db(&mut db)
+        .with_spec_id(LATEST)
+        .with_external_context(CallTracer::default())
+        .append_handler_register(inspector_handle_register)
+        .modify_cfg_env(|cfg| {
+            configure(cfg);
+            if let Some(chain_id) = args.chain_id {
+                cfg.chain_id = chain_id;
+            }
+        })
+        .modify_block_env(|block: &mut BlockEnv| {
+            if let Some(number) = args.block_number {
+                block.number = U256::from(number);
+            }
+            if let Some(timestamp) = args.timestamp {
+                block.timestamp = U256::from(timestamp);
+            }
+            if let Some(basefee) = args.basefee {
+                block.basefee = basefee;
+            }
+        })
+        .modify_tx_env(|tx: &mut TxEnv| {
+            tx.caller = args.caller;
+            tx.transact_to = TxKind::Call(target);
+            tx.data = data.clone();
+            tx.value = args.value;
+            tx.gas_limit = args.gas_limit;
+            tx.gas_price = args.gas_price;
+        })
+        .build();
+
+    let outcome = evm
+        .transact()
+        .map_err(|e| format!("Transaction rejected: {:?}", e))?;
+    let frames = std::mem::take(&mut evm.context.external.frames);
+    drop(evm);
+
+    // Diffed before committing, so values the call was first to load from the prestate
+    // are read from it rather than taken as new.
+    let state_diff = diff_transaction(&db, &outcome.state);
+    db.commit(outcome.state);
+    let result = outcome.result;
+
+    let (status, halt_reason) = match &result {
+        ExecutionResult::Success { .. } => ("success", None),
+        ExecutionResult::Revert { .. } => ("revert", None),
+        ExecutionResult::Halt { reason, .. } => ("halt", Some(format!("{:?}", reason))),
+    };
+    let output = result.output().cloned().unwrap_or_default();
+    let decoded = match (&result, &function) {
+        (ExecutionResult::Success { .. }, Some(function)) => {
+            calldata::decode_output(function, &output)
+        }
+        (ExecutionResult::Revert { .. }, _) => {
+            decode_revert_reason(&output).map(|reason| vec![reason])
+        }
+        _ => None,
+    };
+
+    Ok(Report {
+        status,
+        halt_reason,
+        target,
+        deployed,
+        decoded,
+        gas: GasBreakdown::of_call(args.gas_limit, &data, &result),
+        logs: result
+            .logs()
+            .iter()
+            .map(|log| ReplayLog {
+                address: log.address,
+                topics: log.topics().to_vec(),
+                data: log.data.data.clone(),
+            })
+            .collect(),
+        state_diff,
+        output,
+        trace: args.trace.then_some(frames),
+    })
+}
+
+fn print_text(report: &Report) {
+    match &report.halt_reason {
+        Some(reason) => println!("status     {} ({})", report.status, reason),
+        None => println!("status     {}", report.status),
+    }
+    let origin = if report.deployed { "deployed" } else { "prestate" };
+    println!("target     {} ({})", report.target, origin);
+    println!("output     {}", report.output);
+    if let Some(decoded) = &report.decoded {
+        println!("decoded    {}", decoded.join(", "));
+    }
+
+    let gas = &report.gas;
+    println!(
+        "gas        {} used of {}: {} intrinsic, {} execution, {} refunded",
+        gas.used, gas.limit, gas.intrinsic, gas.execution, gas.refunded
+    );
+
+    println!("logs       {}", report.logs.len());
+    for log in &report.logs {
+        println!("  {}", log.address);
+        for topic in &log.topics {
+            println!("    topic  {}", topic);
+        }
+        println!("    data   {}", log.data);
+    }
+
+    println!("state diff");
+    for line in report.state_diff.to_json().lines() {
+        println!("  {}", line);
+    }
+
+    if let Some(frames) = &report.trace {
+        println!("trace");
+        for frame in frames {
+            let to = frame.to.map_or("?".to_string(), |to| to.to_string());
+            println!(
+                "  {}{} {} -> {} value {} gas {}/{} {}",
+                "  ".repeat(frame.depth),
+                frame.kind,
+                frame.from,
+                to,
+                frame.value,
+                frame.gas_used,
+                frame.gas_limit,
+                if frame.success { "ok" } else { "failed" },
+            );
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    match run(&args) {
+        Ok(report) if args.json => {
+            println!("{}", serde_json::to_string_pretty(&report).unwrap());
+            ExitCode::SUCCESS
+        }
+        Ok(report) => {
+            print_text(&report);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Calldata from the command line: raw hex, or a function signature and its arguments.
+
+use alloy_dyn_abi::{DynSolValue, JsonAbiExt, Specifier};
+use alloy_json_abi::Function;
+use revm::primitives::{hex, Bytes};
+
+/// Encodes the call, returning the parsed function when there is a signature to decode
+/// the output with.
+pub fn encode(
+    raw: Option<&str>,
+    signature: Option<&str>,
+    args: &[String],
+) -> Result<(Bytes, Option<Function>), String> {
+    let Some(signature) = signature else {
+        let data = match raw {
+            Some(raw) => hex::decode(raw).map_err(|e| format!("Invalid calldata: {}", e))?,
+            None => vec![],
+        };
+        return Ok((data.into(), None));
+    };
+
+    let function = Function::parse(signature)
+        .map_err(|e| format!("Invalid signature {}: {}", signature, e))?;
+    if function.inputs.len() != args.len() {
+        return Err(format!(
+            "{} takes {} arguments, got {}",
+            function.name,
+            function.inputs.len(),
+            args.len()
+        ));
+    }
+
+    let values = function
+        .inputs
+        .iter()
+        .zip(args)
+        .map(|(param, arg)| {
+            let ty = param.resolve().map_err(|e| e.to_string())?;
+            ty.coerce_str(arg)
+                .map_err(|e| format!("Invalid {} argument {}: {}", param.ty, arg, e))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    let data = function
+        .abi_encode_input(&values)
+        .map_err(|e| e.to_string())?;
+
+    Ok((data.into(), Some(function)))
+}
+
+/// Decodes `output` with the function's return types, if it has any and they fit.
+pub fn decode_output(function: &Function, output: &[u8]) -> Option<Vec<String>> {
+    if function.outputs.is_empty() {
+        return None;
+    }
+
+    let values = function.abi_decode_output(output, true).ok()?;
+    Some(values.iter().map(format_value).collect())
+}
+
+fn format_value(value: &DynSolValue) -> String {
+    let join = |values: &[DynSolValue]| {
+        values.iter().map(format_value).collect::<Vec<_>>().join(", ")
+    };
+
+    match value {
+        DynSolValue::Bool(b) => b.to_string(),
+        DynSolValue::Int(i, _) => i.to_string(),
+        DynSolValue::Uint(u, _) => u.to_string(),
+        DynSolValue::FixedBytes(word, size) => hex::encode_prefixed(&word[..*size]),
+        DynSolValue::Address(address) => address.to_string(),
+        DynSolValue::Bytes(bytes) => hex::encode_prefixed(bytes),
+        DynSolValue::String(s) => format!("{:?}", s),
+        DynSolValue::Array(values) | DynSolValue::FixedArray(values) => {
+            format!("[{}]", join(values))
+        }
+        DynSolValue::Tuple(values) => format!("({})", join(values)),
+        value => format!("{:?}", value),
+    }
+}
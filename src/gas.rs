@@ -0,0 +1,42 @@
+//! Where a transaction's gas went: the breakdown `stylus-run` reports, and what the refund
+//! tests check their numbers against.
+
+use revm::{
+    interpreter::gas::validate_initial_tx_gas,
+    primitives::{ExecutionResult, SpecId::LATEST},
+};
+use serde::Serialize;
+
+/// Where a call's gas went. Execution is what the call used before its refund.
+#[derive(Debug, Serialize)]
+pub struct GasBreakdown {
+    pub limit: u64,
+    pub intrinsic: u64,
+    pub execution: u64,
+    pub refunded: u64,
+    pub used: u64,
+}
+
+impl GasBreakdown {
+    /// Breaks down a call with `data` and a gas limit of `limit` that ended in `result`.
+    pub fn of_call(limit: u64, data: &[u8], result: &ExecutionResult) -> Self {
+        let intrinsic = validate_initial_tx_gas(LATEST, data, false, &[], 0);
+        let refunded = match result {
+            ExecutionResult::Success { gas_refunded, .. } => *gas_refunded,
+            _ => 0,
+        };
+
+        Self {
+            limit,
+            intrinsic,
+            execution: result.gas_used() + refunded - intrinsic,
+            refunded,
+            used: result.gas_used(),
+        }
+    }
+
+    /// The largest refund EIP-3529 allows: a fifth of the gas used before refunding.
+    pub fn refund_cap(&self) -> u64 {
+        (self.intrinsic + self.execution) / 5
+    }
+}
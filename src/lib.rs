@@ -0,0 +1,181 @@
+//! What the integration tests and `stylus-run` share: deploying programs and contracts into
+//! a `CacheDB`, and the state, gas and replay tooling built around it.
+//!
+//! Deploying a program only stores its code. Nitro activates a program the first time it
+//! is called, so a program that fails activation deploys fine and fails when called.
+
+use std::io::Write;
+
+use revm::{
+    db::CacheDB,
+    primitives::{
+        address, bytes::Bytes, AccountInfo, Address, CfgEnv, ExecutionResult, SpecId::LATEST,
+        TxEnv, TxKind, U256,
+    },
+    DatabaseRef, STYLUS_MAGIC_BYTES,
+};
+
+pub mod calldata;
+pub mod gas;
+pub mod multicall;
+pub mod replay;
+pub mod state_diff;
+pub mod state_dump;
+pub mod trace;
+
+pub const DEPLOYER: Address = address!("Bd770416a3345F91E4B34576cb804a576fa48EB1");
+
+/// EVM child runtime: `mstore(0, 42) return(0, 32)`. It has no constructor, so unlike
+/// solc output it accepts an endowment.
+pub const EVM_CHILD_RUNTIME: &[u8] = &[0x60, 0x2a, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3];
+
+/// Code size limit used when deploying programs. Nitro applies the EIP-170 limit (0x6000)
+/// to brotli-compressed programs, but the harness deploys them uncompressed, so the limit
+/// is raised to fit the fixtures.
+///
+/// The limit bounds the stored code, while [`MAX_WASM_SIZE`] bounds the WASM when the
+/// program activates. The two differ by the compression ratio. An uncompressed program is
+/// its WASM behind [`STYLUS_MAGIC_BYTES`], so it can't be too large to activate; one
+/// deployed through [`compress_wasm`] can.
+pub const STYLUS_MAX_CODE_SIZE: usize = 0x6000 * 4;
+
+/// EIP-3860 init code limit that follows from [`STYLUS_MAX_CODE_SIZE`]; revm always allows
+/// twice the code size limit.
+pub const STYLUS_MAX_INITCODE_SIZE: usize = 2 * STYLUS_MAX_CODE_SIZE;
+
+/// Largest WASM Nitro activates, measured after decompression.
+pub const MAX_WASM_SIZE: usize = 128 * 1024;
+
+/// Funds [`DEPLOYER`] with 1 ETH.
+pub fn setup_simple_test<T>(db: &mut CacheDB<T>) {
+    let mut info = AccountInfo::default();
+    info.balance = U256::from(1e18);
+    db.insert_account_info(DEPLOYER, info);
+}
+
+/// Applies the chain configuration programs are deployed and called under.
+pub fn configure(cfg: &mut CfgEnv) {
+    cfg.limit_contract_code_size = Some(STYLUS_MAX_CODE_SIZE);
+}
+
+pub fn deploy_wasm<T: DatabaseRef>(
+    db: &mut CacheDB<T>,
+    bytecode: Vec<u8>,
+    deployer: Address,
+) -> Address {
+    let (deployed_address, result) = try_deploy_wasm(db, bytecode, deployer);
+
+    if result.is_none() {
+        panic!("Failed to deploy contract");
+    }
+
+    deployed_address
+}
+
+/// Deploys `bytecode` like [`deploy_wasm`], returning the CREATE result instead of only
+/// checking that the transaction was valid. The result is `None` if the transaction itself
+/// was rejected.
+pub fn try_deploy_wasm<T: DatabaseRef>(
+    db: &mut CacheDB<T>,
+    bytecode: Vec<u8>,
+    deployer: Address,
+) -> (Address, Option<ExecutionResult>) {
+    let deployed_address = next_create_address(db, deployer);
+
+    let bytecode = wasm_contract_init_code(bytecode);
+
+    let evm = revm::Evm::builder()
+        .with_db(db)
+        .with_spec_id(LATEST)
+        .modify_tx_env(|tx: &mut TxEnv| {
+            tx.caller = deployer;
+            tx.transact_to = TxKind::Create;
+            tx.data = bytecode.into();
+        })
+        .modify_cfg_env(configure);
+
+    (deployed_address, evm.build().transact_commit().ok())
+}
+
+pub fn deploy_solidity<T: DatabaseRef>(
+    db: &mut CacheDB<T>,
+    bytecode: Vec<u8>,
+    deployer: Address,
+) -> Address {
+    let (deployed_address, result) = try_deploy_solidity(db, bytecode, deployer);
+
+    if result.is_none() {
+        panic!("Failed to deploy contract");
+    }
+
+    deployed_address
+}
+
+/// Deploys EVM init code like [`deploy_solidity`], returning the CREATE result the way
+/// [`try_deploy_wasm`] does.
+pub fn try_deploy_solidity<T: DatabaseRef>(
+    db: &mut CacheDB<T>,
+    bytecode: Vec<u8>,
+    deployer: Address,
+) -> (Address, Option<ExecutionResult>) {
+    let deployed_address = next_create_address(db, deployer);
+
+    let evm = revm::Evm::builder()
+        .with_db(db)
+        .with_spec_id(LATEST)
+        .modify_tx_env(|tx: &mut TxEnv| {
+            tx.caller = deployer;
+            tx.transact_to = TxKind::Create;
+            tx.data = bytecode.into();
+        });
+
+    (deployed_address, evm.build().transact_commit().ok())
+}
+
+/// Address the next CREATE from `creator` lands at, given its nonce in `db`.
+pub fn next_create_address<T: DatabaseRef>(db: &CacheDB<T>, creator: Address) -> Address {
+    let nonce = db
+        .basic_ref(creator)
+        .ok()
+        .flatten()
+        .map(|info| info.nonce)
+        .unwrap_or_default();
+    creator.create(nonce)
+}
+
+/// Dictionary byte Nitro writes after [`STYLUS_MAGIC_BYTES`] for a program brotli-compressed
+/// without a dictionary.
+pub const STYLUS_EMPTY_DICTIONARY: u8 = 0x00;
+
+/// `wasm` compressed the way Nitro's tooling deploys programs: the dictionary byte, then the
+/// brotli stream. Deploy it like uncompressed WASM; arbos-revm decompresses it on activation.
+pub fn compress_wasm(wasm: &[u8]) -> Vec<u8> {
+    let mut compressed = vec![STYLUS_EMPTY_DICTIONARY];
+    {
+        let mut writer = brotli::CompressorWriter::new(&mut compressed, 4096, 11, 22);
+        writer.write_all(wasm).unwrap();
+    }
+    compressed
+}
+
+pub fn wasm_contract_init_code(bytecode: Vec<u8>) -> Vec<u8> {
+    evm_contract_init_code([Bytes::from(STYLUS_MAGIC_BYTES), Bytes::from(bytecode)].concat())
+}
+
+/// Init code that returns `bytecode` as the deployed code, without running a constructor.
+pub fn evm_contract_init_code(mut bytecode: Vec<u8>) -> Vec<u8> {
+    let mut deploy = vec![];
+    deploy.push(revm::interpreter::opcode::PUSH32);
+    deploy.append(&mut U256::from(bytecode.len()).to_be_bytes_vec());
+    deploy.push(revm::interpreter::opcode::DUP1);
+    deploy.push(revm::interpreter::opcode::PUSH1);
+    deploy.push(42);
+    deploy.push(revm::interpreter::opcode::PUSH1);
+    deploy.push(0);
+    deploy.push(revm::interpreter::opcode::CODECOPY);
+    deploy.push(revm::interpreter::opcode::PUSH1);
+    deploy.push(0);
+    deploy.push(revm::interpreter::opcode::RETURN);
+    deploy.append(&mut bytecode);
+    deploy
+}
@@ -0,0 +1,49 @@
+//! The interface multicall.wasm and Multicaller.sol share, and the calls the tests and
+//! benches build with it. `tests/abi.rs` checks it against the ABI solc emits for
+//! Multicaller.sol.
+
+use alloy_sol_types::{sol, SolCall};
+use revm::primitives::{Address, Bytes, U256};
+
+sol! {
+    contract Multicaller {
+        enum CallType {
+            CALL,
+            DELEGATECALL,
+            STATICCALL
+        }
+
+        struct Call {
+            CallType callType;
+            address target;
+            bytes data;
+            uint256 value;
+            uint256 gas_limit;
+        }
+
+        function multicall(Call[] memory calls) external payable returns (bytes[] memory results);
+    }
+}
+
+/// A call of `call_type` to `target` without value, forwarding all available gas.
+pub fn call(call_type: Multicaller::CallType, target: Address, data: Vec<u8>) -> Multicaller::Call {
+    Multicaller::Call {
+        callType: call_type,
+        target,
+        data: data.into(),
+        value: U256::ZERO,
+        gas_limit: U256::ZERO,
+    }
+}
+
+/// Calldata that has the multicaller make `calls` in order, reverting if any of them fails.
+pub fn multicall(calls: Vec<Multicaller::Call>) -> Vec<u8> {
+    Multicaller::multicallCall { calls }.abi_encode()
+}
+
+/// What each call returned, from the output of a successful `multicall`.
+pub fn results(output: &[u8]) -> Vec<Bytes> {
+    Multicaller::multicallCall::abi_decode_returns(output, true)
+        .unwrap()
+        .results
+}
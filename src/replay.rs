@@ -0,0 +1,281 @@
+//! Replays recorded transactions and compares the results with the receipts a Nitro node
+//! produced for them.
+//!
+//! A bundle holds the prestate (in any format [`StateDump`] loads), the block the
+//! transactions ran in, the transactions in order and one expected receipt per
+//! transaction. Receipt gas is Nitro's, which includes the L1 poster's share; that part is
+//! recorded separately as `gasUsedForL1` and left out of the comparison, since arbos-revm
+//! only executes the L2 part.
+//!
+//! Transactions carry their EIP-2718 `type` and run with the bundle's `chainId`. Only the
+//! Ethereum types execute; Arbitrum's own types (deposits, retryables, internal txs) need
+//! Nitro's transaction processor around the EVM, so they are reported as rejected. A
+//! rejected transaction leaves the state as it was and shows up as a mismatch.
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use revm::{
+    db::CacheDB,
+    primitives::{
+        Address, BlockEnv, Bytes, CfgEnv, ExecutionResult, Log, TxEnv, TxKind, B256, U256,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+use crate::state_dump::{deserialize_u64, DumpAccount, StateDump};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayBlock {
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub number: u64,
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub timestamp: u64,
+    #[serde(default)]
+    pub coinbase: Address,
+    #[serde(default)]
+    pub base_fee_per_gas: U256,
+    pub gas_limit: U256,
+}
+
+/// First EIP-2718 type Arbitrum reserves for its own transactions.
+const FIRST_ARBITRUM_TX_TYPE: u64 = 0x64;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayTx {
+    /// EIP-2718 type; legacy transactions are 0.
+    #[serde(rename = "type", default, deserialize_with = "deserialize_u64")]
+    pub tx_type: u64,
+    pub from: Address,
+    /// `None` for contract creations.
+    pub to: Option<Address>,
+    #[serde(default)]
+    pub input: Bytes,
+    #[serde(default)]
+    pub value: U256,
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub gas: u64,
+    /// The price the transaction paid, which for EIP-1559 transactions is the effective
+    /// gas price.
+    pub gas_price: U256,
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub nonce: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayLog {
+    pub address: Address,
+    pub topics: Vec<B256>,
+    pub data: Bytes,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpectedReceipt {
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub status: u64,
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub gas_used: u64,
+    #[serde(default, deserialize_with = "deserialize_u64")]
+    pub gas_used_for_l1: u64,
+    #[serde(default)]
+    pub logs: Vec<ReplayLog>,
+    /// Return or revert data. Receipts don't carry it, so it is only checked when a bundle
+    /// records it from a trace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<Bytes>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReplayBundle {
+    #[serde(rename = "chainId", default = "default_chain_id", deserialize_with = "deserialize_u64")]
+    pub chain_id: u64,
+    pub prestate: BTreeMap<Address, DumpAccount>,
+    pub block: ReplayBlock,
+    pub transactions: Vec<ReplayTx>,
+    pub receipts: Vec<ExpectedReceipt>,
+}
+
+impl ReplayLog {
+    fn from_log(log: &Log) -> Self {
+        Self {
+            address: log.address,
+            topics: log.topics().to_vec(),
+            data: log.data.data.clone(),
+        }
+    }
+}
+
+impl ExpectedReceipt {
+    fn from_result(result: &ExecutionResult) -> Self {
+        Self {
+            status: result.is_success() as u64,
+            gas_used: result.gas_used(),
+            gas_used_for_l1: 0,
+            logs: result.logs().iter().map(ReplayLog::from_log).collect(),
+            output: result.output().cloned(),
+        }
+    }
+}
+
+fn default_chain_id() -> u64 {
+    CfgEnv::default().chain_id
+}
+
+/// Executes `tx`, or says why it could not be.
+fn execute(
+    db: &mut CacheDB<StateDump>,
+    chain_id: u64,
+    block: &ReplayBlock,
+    tx: &ReplayTx,
+) -> Result<ExecutionResult, String> {
+    if tx.tx_type >= FIRST_ARBITRUM_TX_TYPE {
+        return Err(format!("Arbitrum tx type {:#x} can't be replayed", tx.tx_type));
+    }
+
+    let evm = revm::Evm::builder()
+        .with_db(db)
+        .modify_cfg_env(|cfg: &mut CfgEnv| {
+            cfg.chain_id = chain_id;
+        })
+        .modify_block_env(|env: &mut BlockEnv| {
+            env.number = U256::from(block.number);
+            env.timestamp = U256::from(block.timestamp);
+            env.coinbase = block.coinbase;
+            env.basefee = block.base_fee_per_gas;
+            env.gas_limit = block.gas_limit;
+        })
+        .modify_tx_env(|env: &mut TxEnv| {
+            env.caller = tx.from;
+            env.transact_to = match tx.to {
+                Some(to) => TxKind::Call(to),
+                None => TxKind::Create,
+            };
+            env.data = tx.input.clone();
+            env.value = tx.value;
+            env.gas_limit = tx.gas;
+            env.gas_price = tx.gas_price;
+            env.nonce = Some(tx.nonce);
+        });
+
+    evm.build()
+        .transact_commit()
+        .map_err(|e| format!("{:?}", e))
+}
+
+impl ReplayBundle {
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("Failed to read {}: {}", path.display(), e));
+
+        serde_json::from_str(&json)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", path.display(), e))
+    }
+
+    /// Runs `transactions` from `prestate` and records their results as the expected
+    /// receipts, for building bundles out of harness scenarios. Fails if a transaction is
+    /// rejected, since it would have no receipt.
+    pub fn record(
+        chain_id: u64,
+        prestate: BTreeMap<Address, DumpAccount>,
+        block: ReplayBlock,
+        transactions: Vec<ReplayTx>,
+    ) -> Result<Self, String> {
+        let mut bundle = Self {
+            chain_id,
+            prestate,
+            block,
+            transactions,
+            receipts: vec![],
+        };
+        bundle.receipts = bundle
+            .replay()
+            .into_iter()
+            .enumerate()
+            .map(|(index, result)| match result {
+                Ok(result) => Ok(ExpectedReceipt::from_result(&result)),
+                Err(e) => Err(format!("tx {} was rejected: {}", index, e)),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(bundle)
+    }
+
+    /// Executes every transaction in order on top of the prestate. Rejected transactions
+    /// leave the state untouched.
+    pub fn replay(&self) -> Vec<Result<ExecutionResult, String>> {
+        let mut db = CacheDB::new(StateDump::from_accounts(self.prestate.clone()));
+
+        self.transactions
+            .iter()
+            .map(|tx| execute(&mut db, self.chain_id, &self.block, tx))
+            .collect()
+    }
+
+    /// Differences between the replayed results and the expected receipts, one line each.
+    pub fn mismatches(&self) -> Vec<String> {
+        assert_eq!(
+            self.transactions.len(),
+            self.receipts.len(),
+            "Bundle needs one receipt per transaction"
+        );
+
+        let mut mismatches = vec![];
+        let mut mismatch = |index: usize, field: &str, expected: String, actual: String| {
+            if expected != actual {
+                mismatches.push(format!(
+                    "tx {}: {} expected {}, got {}",
+                    index, field, expected, actual
+                ));
+            }
+        };
+
+        for (index, (result, expected)) in self.replay().iter().zip(&self.receipts).enumerate() {
+            let actual = match result {
+                Ok(result) => ExpectedReceipt::from_result(result),
+                Err(e) => {
+                    mismatch(index, "result", "a receipt".to_string(), format!("rejected: {}", e));
+                    continue;
+                }
+            };
+
+            mismatch(index, "status", expected.status.to_string(), actual.status.to_string());
+            match expected.gas_used.checked_sub(expected.gas_used_for_l1) {
+                Some(gas_used) => mismatch(
+                    index,
+                    "gas used",
+                    gas_used.to_string(),
+                    actual.gas_used.to_string(),
+                ),
+                None => mismatch(
+                    index,
+                    "gas used for L1",
+                    format!("at most {}", expected.gas_used),
+                    expected.gas_used_for_l1.to_string(),
+                ),
+            }
+            mismatch(
+                index,
+                "logs",
+                format!("{:?}", expected.logs),
+                format!("{:?}", actual.logs),
+            );
+            if let Some(output) = &expected.output {
+                mismatch(
+                    index,
+                    "output",
+                    output.to_string(),
+                    actual.output.unwrap_or_default().to_string(),
+                );
+            }
+        }
+
+        mismatches
+    }
+
+    pub fn assert_matches(&self) {
+        let mismatches = self.mismatches();
+        assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+    }
+}
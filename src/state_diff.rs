@@ -0,0 +1,220 @@
+//! Whole-state assertions: diff what a transaction changes against the state it ran on and
+//! compare the structured difference, usually against a golden file in `tests/golden`.
+//!
+//! Golden files are plain JSON. Run with `UPDATE_GOLDEN=1` to rewrite them from the diffs
+//! the tests produce, then review the changes like any other.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Debug,
+    fs,
+    path::PathBuf,
+};
+
+use revm::{
+    primitives::{AccountInfo, Address, Bytes, EvmState, KECCAK_EMPTY, U256},
+    DatabaseRef,
+};
+use serde::{Deserialize, Serialize};
+
+/// An account as the snapshot sees it. Empty accounts count as nonexistent, as EIP-161
+/// has them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct AccountSnapshot {
+    balance: U256,
+    nonce: u64,
+    code: Bytes,
+    /// Non-zero slots only.
+    storage: BTreeMap<U256, U256>,
+}
+
+impl AccountSnapshot {
+    fn is_empty(&self) -> bool {
+        self.balance.is_zero() && self.nonce == 0 && self.code.is_empty() && self.storage.is_empty()
+    }
+}
+
+/// The accounts a transaction touched, on one side of it.
+#[derive(Clone, Debug, Default)]
+struct Snapshot {
+    accounts: BTreeMap<Address, AccountSnapshot>,
+}
+
+/// The changes a transaction makes, from the state revm returns for it and the database it
+/// ran against, before that state is committed.
+///
+/// Values before the transaction are read through `db`, so an account or slot the
+/// transaction was the first to load from a backing database compares against its real
+/// value. Only slots the transaction touched are covered, including for accounts it
+/// destroyed.
+pub fn diff_transaction<D: DatabaseRef>(db: &D, state: &EvmState) -> StateDiff
+where
+    D::Error: Debug,
+{
+    let mut before = Snapshot::default();
+    let mut after = Snapshot::default();
+
+    for (address, account) in state {
+        if !account.is_touched() {
+            continue;
+        }
+
+        let info = db.basic_ref(*address).unwrap().unwrap_or_default();
+        let mut from = AccountSnapshot {
+            balance: info.balance,
+            nonce: info.nonce,
+            code: code_of(db, &info),
+            storage: BTreeMap::new(),
+        };
+        let mut to = AccountSnapshot::default();
+        if !account.is_selfdestructed() {
+            to.balance = account.info.balance;
+            to.nonce = account.info.nonce;
+            to.code = code_of(db, &account.info);
+        }
+
+        for (slot, value) in &account.storage {
+            let original = db.storage_ref(*address, *slot).unwrap();
+            if !original.is_zero() {
+                from.storage.insert(*slot, original);
+            }
+            if !account.is_selfdestructed() && !value.present_value.is_zero() {
+                to.storage.insert(*slot, value.present_value);
+            }
+        }
+
+        if !from.is_empty() {
+            before.accounts.insert(*address, from);
+        }
+        if !to.is_empty() {
+            after.accounts.insert(*address, to);
+        }
+    }
+
+    before.diff(&after)
+}
+
+fn code_of<D: DatabaseRef>(db: &D, info: &AccountInfo) -> Bytes
+where
+    D::Error: Debug,
+{
+    match &info.code {
+        Some(code) => code.original_bytes(),
+        None if info.code_hash == KECCAK_EMPTY => Bytes::new(),
+        None => db.code_by_hash_ref(info.code_hash).unwrap().original_bytes(),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Change<T> {
+    pub from: T,
+    pub to: T,
+}
+
+impl<T: PartialEq> Change<T> {
+    fn between(from: T, to: T) -> Option<Self> {
+        (from != to).then_some(Self { from, to })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AccountDiff {
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub created: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub destroyed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance: Option<Change<U256>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<Change<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<Change<Bytes>>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub storage: BTreeMap<U256, Change<U256>>,
+}
+
+/// Every account whose state a transaction changed. Unchanged accounts are left out, so an
+/// empty diff means the state is untouched.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StateDiff {
+    pub accounts: BTreeMap<Address, AccountDiff>,
+}
+
+impl Snapshot {
+    /// The changes that turn `self` into `after`.
+    fn diff(&self, after: &Snapshot) -> StateDiff {
+        let absent = AccountSnapshot::default();
+        let mut accounts = BTreeMap::new();
+
+        let addresses: BTreeSet<_> = self.accounts.keys().chain(after.accounts.keys()).collect();
+        for address in addresses {
+            let before_account = self.accounts.get(address);
+            let after_account = after.accounts.get(address);
+            let from = before_account.unwrap_or(&absent);
+            let to = after_account.unwrap_or(&absent);
+
+            if from == to {
+                continue;
+            }
+
+            let slots: BTreeSet<_> = from.storage.keys().chain(to.storage.keys()).collect();
+            let storage = slots
+                .into_iter()
+                .filter_map(|slot| {
+                    let change = Change::between(
+                        from.storage.get(slot).copied().unwrap_or_default(),
+                        to.storage.get(slot).copied().unwrap_or_default(),
+                    )?;
+                    Some((*slot, change))
+                })
+                .collect();
+
+            let diff = AccountDiff {
+                created: before_account.is_none(),
+                destroyed: after_account.is_none(),
+                balance: Change::between(from.balance, to.balance),
+                nonce: Change::between(from.nonce, to.nonce),
+                code: Change::between(from.code.clone(), to.code.clone()),
+                storage,
+            };
+            accounts.insert(*address, diff);
+        }
+
+        StateDiff { accounts }
+    }
+}
+
+impl StateDiff {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// Asserts the diff matches `tests/golden/<name>.json`, or rewrites that file when
+    /// `UPDATE_GOLDEN` is set.
+    pub fn assert_golden(&self, name: &str) {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/golden")
+            .join(format!("{}.json", name));
+
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, self.to_json() + "\n").unwrap();
+            return;
+        }
+
+        let golden = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("Failed to read {}: {}", path.display(), e));
+        let expected: StateDiff = serde_json::from_str(&golden)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", path.display(), e));
+
+        assert!(
+            *self == expected,
+            "State diff doesn't match {}\nexpected: {}\nactual: {}",
+            path.display(),
+            expected.to_json(),
+            self.to_json()
+        );
+    }
+}
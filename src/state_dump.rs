@@ -0,0 +1,192 @@
+//! A read-only `Database` backed by a JSON state dump, for reproducing mainnet or testnet
+//! scenarios offline. Wrap it in a `CacheDB` like `EmptyDB`.
+//!
+//! Accepts geth-style genesis files (`{"alloc": {...}}`), anvil-style dumps
+//! (`{"accounts": {...}}`) and bare address maps. ArbOS state is just the storage of
+//! `ARBOS_STATE_ADDRESS`, so it is carried like any other account.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    convert::Infallible,
+    fs,
+    path::Path,
+};
+
+use revm::{
+    db::{AccountState, CacheDB},
+    primitives::{keccak256, AccountInfo, Address, Bytecode, Bytes, B256, KECCAK_EMPTY, U256},
+    Database, DatabaseRef,
+};
+use serde::{Deserialize, Serialize};
+
+/// Quantities like nonces appear as numbers in anvil dumps and as hex strings in geth's.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Quantity {
+    Number(u64),
+    Hex(U256),
+}
+
+pub fn deserialize_u64<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    match Quantity::deserialize(deserializer)? {
+        Quantity::Number(value) => Ok(value),
+        Quantity::Hex(value) => value.try_into().map_err(serde::de::Error::custom),
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DumpAccount {
+    pub balance: U256,
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub nonce: u64,
+    #[serde(skip_serializing_if = "Bytes::is_empty")]
+    pub code: Bytes,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub storage: BTreeMap<U256, U256>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DumpFile {
+    Genesis { alloc: BTreeMap<Address, DumpAccount> },
+    Anvil { accounts: BTreeMap<Address, DumpAccount> },
+    Bare(BTreeMap<Address, DumpAccount>),
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct StateDump {
+    accounts: HashMap<Address, AccountInfo>,
+    storage: HashMap<Address, HashMap<U256, U256>>,
+    contracts: HashMap<B256, Bytecode>,
+}
+
+impl StateDump {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+        Self::from_json(&json).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let dump = serde_json::from_str(json).map_err(|e| format!("Invalid state dump: {}", e))?;
+        let accounts = match dump {
+            DumpFile::Genesis { alloc } => alloc,
+            DumpFile::Anvil { accounts } => accounts,
+            DumpFile::Bare(accounts) => accounts,
+        };
+
+        Ok(Self::from_accounts(accounts))
+    }
+
+    pub fn from_accounts(accounts: BTreeMap<Address, DumpAccount>) -> Self {
+        let mut dump = Self::default();
+
+        for (address, account) in accounts {
+            let (code_hash, code) = if account.code.is_empty() {
+                (KECCAK_EMPTY, Bytecode::default())
+            } else {
+                let code = Bytecode::new_raw(account.code);
+                (code.hash_slow(), code)
+            };
+            dump.contracts.insert(code_hash, code.clone());
+
+            dump.accounts.insert(
+                address,
+                AccountInfo {
+                    balance: account.balance,
+                    nonce: account.nonce,
+                    code_hash,
+                    code: Some(code),
+                },
+            );
+            dump.storage
+                .insert(address, account.storage.into_iter().collect());
+        }
+
+        dump
+    }
+}
+
+/// Dumps every existing account `db` holds, in the bare format [`StateDump`] loads.
+pub fn export<T>(db: &CacheDB<T>) -> BTreeMap<Address, DumpAccount> {
+    db.accounts
+        .iter()
+        .filter(|(_, account)| !matches!(account.account_state, AccountState::NotExisting))
+        .map(|(address, account)| {
+            let code = match &account.info.code {
+                Some(code) => code.original_bytes(),
+                None => db
+                    .contracts
+                    .get(&account.info.code_hash)
+                    .map(|code| code.original_bytes())
+                    .unwrap_or_default(),
+            };
+
+            let dump = DumpAccount {
+                balance: account.info.balance,
+                nonce: account.info.nonce,
+                code,
+                storage: account
+                    .storage
+                    .iter()
+                    .filter(|(_, value)| !value.is_zero())
+                    .map(|(slot, value)| (*slot, *value))
+                    .collect(),
+            };
+            (*address, dump)
+        })
+        .collect()
+}
+
+pub fn export_json<T>(db: &CacheDB<T>) -> String {
+    serde_json::to_string_pretty(&export(db)).unwrap()
+}
+
+impl DatabaseRef for StateDump {
+    type Error = Infallible;
+
+    fn basic_ref(&self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        Ok(self.accounts.get(&address).cloned())
+    }
+
+    fn code_by_hash_ref(&self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        Ok(self.contracts.get(&code_hash).cloned().unwrap_or_default())
+    }
+
+    fn storage_ref(&self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        Ok(self
+            .storage
+            .get(&address)
+            .and_then(|storage| storage.get(&index))
+            .copied()
+            .unwrap_or_default())
+    }
+
+    /// Dumps carry no block history, so hashes are derived like `EmptyDB`'s.
+    fn block_hash_ref(&self, number: u64) -> Result<B256, Self::Error> {
+        Ok(keccak256(number.to_string().as_bytes()))
+    }
+}
+
+impl Database for StateDump {
+    type Error = Infallible;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        self.basic_ref(address)
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        self.code_by_hash_ref(code_hash)
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        self.storage_ref(address, index)
+    }
+
+    fn block_hash(&mut self, number: u64) -> Result<B256, Self::Error> {
+        self.block_hash_ref(number)
+    }
+}
@@ -0,0 +1,134 @@
+//! A call tracer: one frame per call or create, in the order they start.
+
+use revm::{
+    interpreter::{
+        CallInputs, CallOutcome, CreateInputs, CreateOutcome, InstructionResult,
+        InterpreterResult,
+    },
+    primitives::{Address, Bytes, CreateScheme, U256},
+    Database, EvmContext, Inspector,
+};
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Frame {
+    pub depth: usize,
+    pub kind: String,
+    pub from: Address,
+    /// `None` for creates that failed before an address was assigned.
+    pub to: Option<Address>,
+    pub value: U256,
+    pub input: Bytes,
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub success: bool,
+    /// How the frame ended, down to the halt reason of a failed frame.
+    #[serde(skip)]
+    pub result: InstructionResult,
+    pub output: Bytes,
+}
+
+#[derive(Debug, Default)]
+pub struct CallTracer {
+    pub frames: Vec<Frame>,
+    /// Indices of the frames that have started but not yet ended.
+    open: Vec<usize>,
+}
+
+impl CallTracer {
+    fn start(
+        &mut self,
+        kind: &str,
+        from: Address,
+        to: Option<Address>,
+        value: U256,
+        input: Bytes,
+        gas_limit: u64,
+    ) {
+        self.open.push(self.frames.len());
+        self.frames.push(Frame {
+            depth: self.open.len() - 1,
+            kind: kind.to_string(),
+            from,
+            to,
+            value,
+            input,
+            gas_limit,
+            gas_used: 0,
+            success: false,
+            result: InstructionResult::Continue,
+            output: Bytes::new(),
+        });
+    }
+
+    fn end(&mut self, to: Option<Address>, result: &InterpreterResult) {
+        let index = self.open.pop().expect("Frame ended without starting");
+        let frame = &mut self.frames[index];
+
+        frame.to = frame.to.or(to);
+        frame.gas_used = result.gas.spent();
+        frame.success = result.is_ok();
+        frame.result = result.result;
+        frame.output = result.output.clone();
+    }
+}
+
+impl<DB: Database> Inspector<DB> for CallTracer {
+    fn call(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        inputs: &mut CallInputs,
+    ) -> Option<CallOutcome> {
+        let kind = format!("{:?}", inputs.scheme).to_uppercase();
+        self.start(
+            &kind,
+            inputs.caller,
+            Some(inputs.target_address),
+            inputs.call_value(),
+            inputs.input.clone(),
+            inputs.gas_limit,
+        );
+        None
+    }
+
+    fn call_end(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        _inputs: &CallInputs,
+        outcome: CallOutcome,
+    ) -> CallOutcome {
+        self.end(None, &outcome.result);
+        outcome
+    }
+
+    fn create(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        inputs: &mut CreateInputs,
+    ) -> Option<CreateOutcome> {
+        let kind = match inputs.scheme {
+            CreateScheme::Create => "CREATE",
+            CreateScheme::Create2 { .. } => "CREATE2",
+        };
+        self.start(
+            kind,
+            inputs.caller,
+            None,
+            inputs.value,
+            inputs.init_code.clone(),
+            inputs.gas_limit,
+        );
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        _inputs: &CreateInputs,
+        outcome: CreateOutcome,
+    ) -> CreateOutcome {
+        self.end(outcome.address, &outcome.result);
+        outcome
+    }
+}
@@ -0,0 +1,43 @@
+#![cfg_attr(not(test), no_main)]
+extern crate alloc;
+
+use stylus_sdk::{alloy_primitives::{B256, U256}, crypto::keccak, evm, prelude::*};
+
+
+#[storage]
+#[entrypoint]
+pub struct BenchProgram;
+
+#[public]
+impl BenchProgram {
+    // Writes count slots, then reads them all back
+    pub fn storageLoop(&mut self, count: u64) -> U256 {
+        for i in 0..count {
+            let key = U256::from(i);
+            unsafe { self.vm().storage_cache_bytes32(key, B256::from(key + U256::from(1))) };
+        }
+
+        let mut sum = U256::ZERO;
+        for i in 0..count {
+            sum += U256::from_be_bytes(self.vm().storage_load_bytes32(U256::from(i)).0);
+        }
+        sum
+    }
+
+    // Hashes seed count times over
+    pub fn keccakLoop(&mut self, seed: B256, count: u64) -> B256 {
+        let mut hash = seed;
+        for _ in 0..count {
+            hash = keccak(hash);
+        }
+        hash
+    }
+
+    // Emits count logs, each with its index as the only topic
+    pub fn emitLogs(&mut self, count: u64) -> Result<(), Vec<u8>> {
+        for i in 0..count {
+            evm::raw_log(&[B256::from(U256::from(i))], &[]).map_err(|e| e.into())?;
+        }
+        Ok(())
+    }
+}
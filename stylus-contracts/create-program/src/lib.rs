@@ -25,6 +25,21 @@ impl CreateProgram {
             Err(error) => Err(error.into())
         }       
     }
+
+    // Like create, but returns the failure instead of reverting so the factory's state persists
+    pub fn tryCreate(&mut self, init_code: Bytes, endowment: U256) -> (Address, Bytes) {
+        match unsafe { self.vm().deploy(init_code.as_slice(), endowment, None) } {
+            Ok(address) => (address, Vec::new().into()),
+            Err(error) => (Address::ZERO, error.into())
+        }
+    }
+
+    pub fn tryCreate2(&mut self, init_code: Bytes, salt: B256, endowment: U256) -> (Address, Bytes) {
+        match unsafe { self.vm().deploy(init_code.as_slice(), endowment, Some(salt)) } {
+            Ok(address) => (address, Vec::new().into()),
+            Err(error) => (Address::ZERO, error.into())
+        }
+    }
 }
 
 
@@ -2,7 +2,7 @@
 extern crate alloc;
 
 use alloy_primitives::Address;
-use stylus_sdk::{abi::Bytes, alloy_primitives::{B256, U256}, deploy::RawDeploy, evm, prelude::*};
+use stylus_sdk::{abi::Bytes, alloy_primitives::{B256, U256}, deploy::RawDeploy, evm, hostio, prelude::*};
 
 
 #[storage]
@@ -14,6 +14,16 @@ impl EmitLog {
     pub fn emitLog(&mut self, topics: Vec<B256>, data: Bytes) -> Result<(), Vec<u8>> {        
         evm::raw_log(&topics, &data).map_err(|e| e.into())
     }
-}
 
+    // Hands buffer (topics followed by data) straight to the emit_log hostio, skipping the
+    // SDK's own checks on the topic count
+    pub fn emitRawLog(&mut self, topics: u64, buffer: Bytes) {
+        unsafe { hostio::emit_log(buffer.as_ptr(), buffer.len(), topics as usize) }
+    }
 
+    // Emits the log, then reverts the frame that emitted it
+    pub fn emitThenRevert(&mut self, topics: Vec<B256>, data: Bytes) -> Result<(), Vec<u8>> {
+        self.emitLog(topics, data)?;
+        Err(Vec::new())
+    }
+}
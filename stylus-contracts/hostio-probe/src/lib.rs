@@ -0,0 +1,115 @@
+#![cfg_attr(not(test), no_main)]
+extern crate alloc;
+
+use alloy_primitives::{Address, B256};
+use stylus_sdk::{
+    abi::Bytes, alloy_primitives::U256, call::RawCall, function_selector, hostio, prelude::*,
+};
+
+
+#[storage]
+#[entrypoint]
+pub struct HostioProbe;
+
+#[public]
+impl HostioProbe {
+    #[payable]
+    pub fn context(&mut self) -> (Address, Address, U256, U256) {
+        (
+            self.vm().msg_sender(),
+            self.vm().tx_origin(),
+            self.vm().msg_value(),
+            self.vm().tx_gas_price(),
+        )
+    }
+
+    pub fn contractAddress(&mut self) -> Address {
+        self.vm().contract_address()
+    }
+
+    pub fn selfBalance(&mut self) -> U256 {
+        let address = self.vm().contract_address();
+        self.vm().balance(address)
+    }
+
+    // Gas spent by two storage loads of slot in a row. A repeat load within one call is
+    // served from the storage cache, so the second load runs in a call of its own.
+    pub fn storageAccessCosts(&mut self, slot: B256) -> (u64, u64) {
+        let first = self.storageLoadCost(slot);
+
+        let selector = function_selector!("storageLoadCost", B256);
+        let calldata = [selector.as_slice(), slot.as_slice()].concat();
+        let contract = self.vm().contract_address();
+        let output = unsafe { RawCall::new().call(contract, &calldata) }.unwrap();
+        let second = U256::from_be_slice(&output).to::<u64>();
+
+        (first, second)
+    }
+
+    // Gas spent by a single storage load of slot
+    pub fn storageLoadCost(&mut self, slot: B256) -> u64 {
+        let key = U256::from_be_bytes(slot.0);
+        let start = self.vm().evm_gas_left();
+        self.vm().storage_load_bytes32(key);
+        start - self.vm().evm_gas_left()
+    }
+
+    // Gas spent by two balance lookups of account in a row
+    pub fn balanceAccessCosts(&mut self, account: Address) -> (u64, u64) {
+        self.costs(|probe| {
+            probe.vm().balance(account);
+        })
+    }
+
+    // Gas spent by two code lookups of account in a row. vm().code() would look up the
+    // code size first, so account_code copies nothing, like EXTCODECOPY of zero bytes.
+    pub fn codeAccessCosts(&mut self, account: Address) -> (u64, u64) {
+        self.costs(|_| {
+            let mut dest = [0u8; 0];
+            unsafe { hostio::account_code(account.as_ptr(), 0, 0, dest.as_mut_ptr()) };
+        })
+    }
+
+    // Gas spent by two empty calls to target in a row
+    pub fn callAccessCosts(&mut self, target: Address) -> (u64, u64) {
+        self.costs(|_| {
+            let _ = unsafe { RawCall::new().call(target, &[]) };
+        })
+    }
+
+    // Calls target with gas (all of it if zero), then reads size bytes of the return data
+    // from offset
+    pub fn returnDataAfterCall(&mut self, target: Address, data: Bytes, gas: u64, offset: u64, size: u64) -> (bool, u64, Bytes) {
+        let call = if gas == 0 { RawCall::new() } else { RawCall::new().gas(gas) };
+        let success = unsafe { call.call(target, &data) }.is_ok();
+        let (length, copied) = self.return_data(offset, size);
+        (success, length, copied)
+    }
+
+    // Deploys init_code, then reads size bytes of the return data from offset
+    pub fn returnDataAfterCreate(&mut self, init_code: Bytes, offset: u64, size: u64) -> (Address, u64, Bytes) {
+        let deployed = unsafe { self.vm().deploy(init_code.as_slice(), U256::ZERO, None) }.unwrap_or_default();
+        let (length, copied) = self.return_data(offset, size);
+        (deployed, length, copied)
+    }
+}
+
+impl HostioProbe {
+    // Runs access twice, returning the gas each run took
+    fn costs(&self, access: impl Fn(&Self)) -> (u64, u64) {
+        let start = self.vm().evm_gas_left();
+        access(self);
+        let first = self.vm().evm_gas_left();
+        access(self);
+        let second = self.vm().evm_gas_left();
+
+        (start - first, first - second)
+    }
+
+    // The return data's size, and the bytes read_return_data copies from it
+    fn return_data(&self, offset: u64, size: u64) -> (u64, Bytes) {
+        let length = self.vm().return_data_size() as u64;
+        let copied = self.vm().read_return_data(offset as usize, Some(size as usize));
+        (length, copied.into())
+    }
+}
@@ -0,0 +1,60 @@
+#![cfg_attr(not(test), no_main)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::{abi::Bytes, call::RawCall, prelude::*};
+
+sol! {
+    interface IRecursive {
+        function recurse(address other, uint256 depth) external returns (uint256);
+    }
+}
+
+#[storage]
+#[entrypoint]
+pub struct Recursive;
+
+#[public]
+impl Recursive {
+    // Bounces between this program and other until depth runs out, reverting if any call fails
+    pub fn recurse(&mut self, other: Address, depth: U256) -> Result<U256, Vec<u8>> {
+        if depth.is_zero() {
+            return Ok(U256::ZERO);
+        }
+
+        let calldata = IRecursive::recurseCall {
+            other: self.vm().contract_address(),
+            depth: depth - U256::from(1),
+        };
+        let output = unsafe { RawCall::new().call(other, &calldata.abi_encode())? };
+        let nested = IRecursive::recurseCall::abi_decode_returns(&output, true)
+            .map_err(|_| Vec::new())?
+            ._0;
+
+        Ok(nested + U256::from(1))
+    }
+
+    // Recurses within the program, without making any calls
+    pub fn recurseInternal(&mut self, depth: u64) -> u64 {
+        descend(depth)
+    }
+
+    // Calls target, returning the failure instead of reverting
+    pub fn tryCall(&mut self, target: Address, data: Bytes) -> (bool, Bytes) {
+        match unsafe { RawCall::new().call(target, &data) } {
+            Ok(output) => (true, output.into()),
+            Err(output) => (false, output.into()),
+        }
+    }
+}
+
+// not a tail call, so every level keeps its frame on the WASM stack
+#[inline(never)]
+fn descend(depth: u64) -> u64 {
+    if depth == 0 {
+        return 0;
+    }
+    core::hint::black_box(descend(depth - 1)) + 1
+}
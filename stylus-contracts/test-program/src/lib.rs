@@ -19,6 +19,23 @@ impl TestProgram {
         unsafe { self.vm().storage_cache_bytes32(key.into(), value) };
     }
 
+    // Writes through to the EVM immediately instead of when the call returns
+    pub fn flushStorage(&mut self, key: B256, value: B256) {
+        unsafe { self.vm().storage_cache_bytes32(key.into(), value) };
+        self.vm().flush_cache(false);
+    }
+
+    pub fn clearStorage(&mut self, keys: Vec<B256>) {
+        for key in keys {
+            unsafe { self.vm().storage_cache_bytes32(key.into(), B256::ZERO) };
+        }
+    }
+
+    pub fn setTransient(&mut self, key: B256, value: B256) {
+        #[allow(unused_unsafe)]
+        unsafe { self.vm().transient_store_bytes32(key.into(), value) };
+    }
+
     pub fn getStorage(&mut self, key: B256) -> B256 {
         self.vm().storage_load_bytes32(key.into()) 
     }
@@ -0,0 +1,48 @@
+use std::collections::BTreeSet;
+
+use alloy_json_abi::JsonAbi;
+use alloy_sol_types::sol;
+use common::multicall::Multicaller;
+
+mod common;
+
+sol!("tests/assets/abi/bench_program.sol");
+sol!("tests/assets/abi/create_program.sol");
+sol!("tests/assets/abi/emit_log.sol");
+sol!("tests/assets/abi/hostio_probe.sol");
+sol!("tests/assets/abi/recursive.sol");
+
+fn solc_selectors(contract: &str) -> BTreeSet<[u8; 4]> {
+    let path = format!("{}/tests/assets/{}.abi", env!("CARGO_MANIFEST_DIR"), contract);
+    let json = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e));
+    let abi: JsonAbi = serde_json::from_str(&json).unwrap();
+
+    abi.functions().map(|function| *function.selector()).collect()
+}
+
+// The tests encode calls with the interfaces generated from each program's exported ABI,
+// so a function a program stops exporting fails to compile wherever it is called. What is
+// left to check is that the Solidity counterparts and the shared Multicaller definition
+// still agree with solc.
+
+#[test]
+pub fn stylus_and_solidity_counterparts_match() {
+    let pairs: &[(&str, &[[u8; 4]], &str)] = &[
+        ("bench_program", IBenchProgram::IBenchProgramCalls::SELECTORS, "BenchProgram"),
+        ("create_program", ICreateProgram::ICreateProgramCalls::SELECTORS, "CreateTest"),
+        ("emit_log", IEmitLog::IEmitLogCalls::SELECTORS, "EmitLog"),
+        ("hostio_probe", IHostioProbe::IHostioProbeCalls::SELECTORS, "HostioProbe"),
+        ("recursive", IRecursive::IRecursiveCalls::SELECTORS, "Recursive"),
+    ];
+
+    for (program, selectors, contract) in pairs {
+        let stylus: BTreeSet<[u8; 4]> = selectors.iter().copied().collect();
+        assert_eq!(stylus, solc_selectors(contract), "{} and {}.sol differ", program, contract);
+    }
+}
+
+#[test]
+pub fn shared_multicaller_matches_solc() {
+    let shared: BTreeSet<[u8; 4]> = Multicaller::MulticallerCalls::SELECTORS.iter().copied().collect();
+    assert_eq!(shared, solc_selectors("Multicaller"));
+}
@@ -0,0 +1,194 @@
+use alloy_sol_types::{sol, SolCall};
+use common::fixtures::{base_state, Fixtures};
+use common::DEPLOYER;
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{address, keccak256, AccessListItem, Address, TxEnv, TxKind, B256};
+
+mod common;
+
+/// An account nothing in the base state touches, so it starts every transaction cold.
+const UNTOUCHED: Address = address!("4242424242424242424242424242424242424242");
+
+const COLD_SLOAD_COST: u64 = 2100;
+const COLD_ACCOUNT_ACCESS_COST: u64 = 2600;
+const WARM_STORAGE_READ_COST: u64 = 100;
+
+sol!("tests/assets/abi/hostio_probe.sol");
+
+fn slot() -> B256 {
+    keccak256("access-costs-slot")
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Vm {
+    Stylus,
+    Evm,
+}
+
+/// What the probe accesses twice, through a hostio in Stylus and the matching opcode in
+/// the EVM.
+#[derive(Clone, Copy, Debug)]
+enum Access {
+    /// `storage_load_bytes32` / `SLOAD` of a slot in the probe. The Stylus probe makes its
+    /// second load from a nested call, past the storage cache of the first.
+    Storage,
+    /// `account_balance` / `BALANCE` of an untouched account.
+    Balance,
+    /// `account_code` / `EXTCODECOPY` of zero bytes of a program nothing else has touched.
+    Code,
+    /// `call_contract` / `CALL` to an untouched account.
+    Call,
+}
+
+const ACCESSES: [Access; 4] = [Access::Storage, Access::Balance, Access::Code, Access::Call];
+
+impl Access {
+    /// Extra gas EIP-2929 charges for the first access in a transaction.
+    fn cold_penalty(self) -> u64 {
+        match self {
+            Access::Storage => COLD_SLOAD_COST - WARM_STORAGE_READ_COST,
+            _ => COLD_ACCOUNT_ACCESS_COST - WARM_STORAGE_READ_COST,
+        }
+    }
+
+    fn calldata(self, fixtures: &Fixtures) -> Vec<u8> {
+        match self {
+            Access::Storage => IHostioProbe::storageAccessCostsCall { slot: slot() }.abi_encode(),
+            Access::Balance => {
+                IHostioProbe::balanceAccessCostsCall { account: UNTOUCHED }.abi_encode()
+            }
+            Access::Code => IHostioProbe::codeAccessCostsCall {
+                account: fixtures.test_program,
+            }
+            .abi_encode(),
+            Access::Call => IHostioProbe::callAccessCostsCall { target: UNTOUCHED }.abi_encode(),
+        }
+    }
+
+    /// The access list entry that warms what this accesses before the transaction starts.
+    fn access_list_item(self, probe: Address, fixtures: &Fixtures) -> AccessListItem {
+        let (address, storage_keys) = match self {
+            Access::Storage => (probe, vec![slot()]),
+            Access::Balance | Access::Call => (UNTOUCHED, vec![]),
+            Access::Code => (fixtures.test_program, vec![]),
+        };
+        AccessListItem { address, storage_keys }
+    }
+}
+
+struct Setup {
+    db: CacheDB<EmptyDB>,
+    fixtures: Fixtures,
+}
+
+impl Setup {
+    fn new() -> Self {
+        let (db, fixtures) = base_state();
+        Self { db, fixtures }
+    }
+
+    fn probe(&self, vm: Vm) -> Address {
+        match vm {
+            Vm::Stylus => self.fixtures.hostio_probe,
+            Vm::Evm => self.fixtures.hostio_probe_evm,
+        }
+    }
+
+    /// Gas the first and second access took within one transaction.
+    fn costs(&mut self, vm: Vm, access: Access, prewarm: bool) -> (u64, u64) {
+        let probe = self.probe(vm);
+        let data = access.calldata(&self.fixtures);
+        let access_list = if prewarm {
+            vec![access.access_list_item(probe, &self.fixtures)]
+        } else {
+            vec![]
+        };
+
+        let mut evm = revm::Evm::builder()
+            .with_db(&mut self.db)
+            .modify_tx_env(|tx: &mut TxEnv| {
+                tx.caller = DEPLOYER;
+                tx.transact_to = TxKind::Call(probe);
+                tx.data = data.into();
+                tx.gas_limit = 10_000_000;
+                tx.access_list = access_list;
+            })
+            .build();
+
+        let result = evm.transact().unwrap().result;
+        assert!(result.is_success(), "{:?} {:?}: {:?}", vm, access, result);
+
+        // Every probe returns the same (uint64, uint64) pair.
+        let costs =
+            IHostioProbe::storageAccessCostsCall::abi_decode_returns(result.output().unwrap(), true)
+                .unwrap();
+        (costs._0, costs._1)
+    }
+}
+
+/// Stylus measures gas by converting ink, which can round each reading down by up to a
+/// gas, so differences of two measurements are only exact to within one gas.
+fn assert_close(stylus: u64, expected: u64, context: String) {
+    assert!(stylus.abs_diff(expected) <= 1, "{}: {} vs {}", context, stylus, expected);
+}
+
+#[test]
+pub fn evm_first_access_is_cold() {
+    let mut setup = Setup::new();
+
+    for access in ACCESSES {
+        let (cold, warm) = setup.costs(Vm::Evm, access, false);
+        assert_eq!(cold - warm, access.cold_penalty(), "{:?}", access);
+    }
+}
+
+#[test]
+pub fn stylus_first_access_is_cold() {
+    let mut setup = Setup::new();
+
+    for access in ACCESSES {
+        let (cold, warm) = setup.costs(Vm::Stylus, access, false);
+        assert!(cold > warm, "{:?}: {} vs {}", access, cold, warm);
+        assert_close(cold - warm, access.cold_penalty(), format!("{:?}", access));
+    }
+}
+
+#[test]
+pub fn access_list_prewarms_accesses() {
+    let mut setup = Setup::new();
+
+    for access in ACCESSES {
+        let (cold, warm) = setup.costs(Vm::Evm, access, true);
+        assert_eq!(cold, warm, "EVM {:?}", access);
+
+        let (cold, warm) = setup.costs(Vm::Stylus, access, true);
+        assert_close(cold, warm, format!("Stylus {:?}", access));
+    }
+}
+
+#[test]
+pub fn access_list_saves_stylus_the_cold_penalty() {
+    let mut setup = Setup::new();
+
+    for access in ACCESSES {
+        let (cold, _) = setup.costs(Vm::Stylus, access, false);
+        let (prewarmed, _) = setup.costs(Vm::Stylus, access, true);
+        assert_close(cold - prewarmed, access.cold_penalty(), format!("{:?}", access));
+    }
+}
+
+#[test]
+pub fn stylus_and_evm_cold_penalties_match() {
+    let mut setup = Setup::new();
+
+    for access in ACCESSES {
+        let (stylus_cold, stylus_warm) = setup.costs(Vm::Stylus, access, false);
+        let (evm_cold, evm_warm) = setup.costs(Vm::Evm, access, false);
+
+        assert_close(
+            stylus_cold - stylus_warm,
+            evm_cold - evm_warm,
+            format!("{:?}", access),
+        );
+    }
+}
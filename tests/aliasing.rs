@@ -0,0 +1,194 @@
+use alloy_sol_types::{sol, SolCall};
+use common::arbitrum::{
+    apply_l1_to_l2_alias, undo_l1_to_l2_alias, ArbSys, ArbitrumTx, ARB_SYS,
+};
+use common::fixtures::base_state;
+use common::hostio_probe::decode_context;
+use common::multicall::{call, multicall, results, Multicaller};
+use common::DEPLOYER;
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{address, Address, ExecutionResult, TxEnv, TxKind, U256};
+
+mod common;
+
+/// An L1 contract sending messages to L2.
+const L1_CONTRACT: Address = address!("8315177aB297bA92A06054cE80a67Ed4DBd7ed3a");
+
+sol!("tests/assets/abi/hostio_probe.sol");
+
+struct AliasingSetup {
+    db: CacheDB<EmptyDB>,
+    multicall: Address,
+    probe: Address,
+    probe_evm: Address,
+}
+
+impl AliasingSetup {
+    fn new() -> Self {
+        let (db, fixtures) = base_state();
+
+        Self {
+            db,
+            multicall: fixtures.multicall,
+            probe: fixtures.hostio_probe,
+            probe_evm: fixtures.hostio_probe_evm,
+        }
+    }
+
+    /// Sends `data` to `to` as an unsigned L1-to-L2 message from `L1_CONTRACT`.
+    fn from_l1(&mut self, to: Address, data: Vec<u8>) -> ExecutionResult {
+        let sender = apply_l1_to_l2_alias(L1_CONTRACT);
+        let nonce = self
+            .db
+            .accounts
+            .get(&sender)
+            .map(|acc| acc.info.nonce)
+            .unwrap_or_default();
+
+        let receipt = ArbitrumTx::unsigned(L1_CONTRACT, to, data)
+            .nonce(nonce)
+            .execute(&mut self.db, U256::ZERO);
+
+        receipt.result.unwrap()
+    }
+
+    fn from_l2(&mut self, to: Address, data: Vec<u8>) -> ExecutionResult {
+        let evm = revm::Evm::builder()
+            .with_db(&mut self.db)
+            .modify_tx_env(|tx: &mut TxEnv| {
+                tx.caller = DEPLOYER;
+                tx.transact_to = TxKind::Call(to);
+                tx.data = data.into();
+                tx.gas_limit = 1_000_000_000;
+            });
+
+        evm.build().transact_commit().unwrap()
+    }
+}
+
+fn forward(target: Address, data: Vec<u8>) -> Vec<u8> {
+    multicall(vec![call(Multicaller::CallType::CALL, target, data)])
+}
+
+fn forwarded_output(result: &ExecutionResult) -> Vec<u8> {
+    assert!(result.is_success(), "{:?}", result);
+    results(result.output().unwrap())[0].to_vec()
+}
+
+#[test]
+pub fn alias_offset_matches_nitro() {
+    assert_eq!(
+        apply_l1_to_l2_alias(Address::ZERO),
+        address!("1111000000000000000000000000000000001111")
+    );
+    assert_eq!(
+        apply_l1_to_l2_alias(L1_CONTRACT),
+        address!("9426177aB297bA92A06054cE80a67Ed4DBd7fE4b")
+    );
+
+    // The offset wraps around at 2^160 in both directions.
+    assert_eq!(
+        apply_l1_to_l2_alias(address!("ffffffffffffffffffffffffffffffffffffffff")),
+        address!("1111000000000000000000000000000000001110")
+    );
+    assert_eq!(
+        undo_l1_to_l2_alias(Address::ZERO),
+        address!("eeeeffffffffffffffffffffffffffffffffeeef")
+    );
+
+    for address in [Address::ZERO, L1_CONTRACT, DEPLOYER, Address::repeat_byte(0xff)] {
+        assert_eq!(undo_l1_to_l2_alias(apply_l1_to_l2_alias(address)), address);
+    }
+}
+
+#[test]
+pub fn stylus_program_sees_aliased_sender() {
+    let mut setup = AliasingSetup::new();
+    let aliased = apply_l1_to_l2_alias(L1_CONTRACT);
+
+    let result = setup.from_l1(setup.probe, IHostioProbe::contextCall {}.abi_encode());
+    assert!(result.is_success(), "{:?}", result);
+
+    let context = decode_context(result.output().unwrap());
+    assert_eq!(context.sender, aliased);
+    assert_eq!(context.origin, aliased);
+}
+
+#[test]
+pub fn nested_stylus_program_sees_aliased_origin() {
+    let mut setup = AliasingSetup::new();
+
+    let result = setup.from_l1(
+        setup.multicall,
+        forward(setup.probe, IHostioProbe::contextCall {}.abi_encode()),
+    );
+    let context = decode_context(&forwarded_output(&result));
+
+    assert_eq!(context.sender, setup.multicall);
+    assert_eq!(context.origin, apply_l1_to_l2_alias(L1_CONTRACT));
+}
+
+#[test]
+pub fn stylus_and_evm_probes_agree() {
+    let mut setup = AliasingSetup::new();
+
+    let stylus = setup.from_l1(setup.probe, IHostioProbe::contextCall {}.abi_encode());
+    let evm = setup.from_l1(setup.probe_evm, IHostioProbe::contextCall {}.abi_encode());
+
+    assert!(stylus.is_success(), "{:?}", stylus);
+    assert!(evm.is_success(), "{:?}", evm);
+    assert_eq!(stylus.output(), evm.output());
+}
+
+#[test]
+pub fn arbsys_reports_aliased_caller() {
+    let mut setup = AliasingSetup::new();
+
+    let aliased = setup.from_l1(
+        setup.multicall,
+        forward(ARB_SYS, ArbSys::wasMyCallersAddressAliasedCall {}.abi_encode()),
+    );
+    assert_eq!(
+        forwarded_output(&aliased),
+        U256::from(1).to_be_bytes_vec()
+    );
+
+    let unaliased = setup.from_l1(
+        setup.multicall,
+        forward(ARB_SYS, ArbSys::myCallersAddressWithoutAliasingCall {}.abi_encode()),
+    );
+    assert_eq!(
+        ArbSys::myCallersAddressWithoutAliasingCall::abi_decode_returns(
+            &forwarded_output(&unaliased),
+            true
+        )
+        .unwrap()
+        ._0,
+        L1_CONTRACT
+    );
+}
+
+#[test]
+pub fn arbsys_reports_unaliased_l2_caller() {
+    let mut setup = AliasingSetup::new();
+
+    let result = setup.from_l2(
+        setup.multicall,
+        forward(ARB_SYS, ArbSys::wasMyCallersAddressAliasedCall {}.abi_encode()),
+    );
+    assert_eq!(forwarded_output(&result), U256::ZERO.to_be_bytes_vec());
+
+    let result = setup.from_l2(
+        setup.multicall,
+        forward(ARB_SYS, ArbSys::myCallersAddressWithoutAliasingCall {}.abi_encode()),
+    );
+    assert_eq!(
+        ArbSys::myCallersAddressWithoutAliasingCall::abi_decode_returns(
+            &forwarded_output(&result),
+            true
+        )
+        .unwrap()
+        ._0,
+        DEPLOYER
+    );
+}
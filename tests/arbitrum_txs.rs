@@ -0,0 +1,254 @@
+use alloy_sol_types::{sol, SolCall};
+use common::arbitrum::{
+    apply_l1_to_l2_alias, ArbRetryableTx, ArbitrumTx, RetryableTicket, ARBOS_ADDRESS,
+    ARB_RETRYABLE_TX, RETRYABLE_LIFETIME_SECONDS,
+};
+use common::hostio_probe::decode_context;
+use common::fixtures::base_state;
+use common::DEPLOYER;
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{
+    address, Address, EVMError, ExecutionResult, InvalidTransaction, TxEnv, TxKind, U256,
+};
+
+mod common;
+
+const L1_SENDER: Address = address!("5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a");
+const BASEFEE: u64 = 100_000_000;
+
+sol!("tests/assets/abi/hostio_probe.sol");
+
+fn setup() -> (CacheDB<EmptyDB>, Address) {
+    let (db, fixtures) = base_state();
+    (db, fixtures.hostio_probe)
+}
+
+fn call(db: &mut CacheDB<EmptyDB>, to: Address, data: Vec<u8>) -> ExecutionResult {
+    let evm = revm::Evm::builder()
+        .with_db(db)
+        .modify_tx_env(|tx: &mut TxEnv| {
+            tx.caller = DEPLOYER;
+            tx.transact_to = TxKind::Call(to);
+            tx.data = data.into();
+        });
+    evm.build().transact_commit().unwrap()
+}
+
+/// The ticket's timeout as `ArbRetryableTx.getTimeout` reports it, if arbos-revm finds it.
+fn ticket_timeout(db: &mut CacheDB<EmptyDB>, ticket: &RetryableTicket) -> Option<U256> {
+    let calldata = ArbRetryableTx::getTimeoutCall { ticketId: ticket.id };
+    let result = call(db, ARB_RETRYABLE_TX, calldata.abi_encode());
+
+    result.is_success().then(|| {
+        ArbRetryableTx::getTimeoutCall::abi_decode_returns(result.output().unwrap(), true)
+            .unwrap()
+            ._0
+    })
+}
+
+fn balance(db: &mut CacheDB<EmptyDB>, address: Address) -> U256 {
+    db.load_account(address).unwrap().info.balance
+}
+
+#[test]
+pub fn deposit_credits_program_without_executing_it() {
+    let (mut db, probe) = setup();
+    let value = U256::from(1e18);
+
+    let receipt = ArbitrumTx::deposit(L1_SENDER, probe, value).execute(&mut db, U256::from(BASEFEE));
+
+    assert!(receipt.result.is_none());
+    assert_eq!(db.load_account(probe).unwrap().info.balance, value);
+    assert_eq!(
+        db.load_account(apply_l1_to_l2_alias(L1_SENDER)).unwrap().info.balance,
+        U256::ZERO
+    );
+
+    let evm = revm::Evm::builder()
+        .with_db(&mut db)
+        .modify_tx_env(|tx: &mut TxEnv| {
+            tx.caller = DEPLOYER;
+            tx.transact_to = TxKind::Call(probe);
+            tx.data = IHostioProbe::selfBalanceCall {}.abi_encode().into();
+        });
+    let result = evm.build().transact_commit().unwrap();
+
+    assert_eq!(result.output().unwrap().to_vec(), value.to_be_bytes_vec());
+}
+
+#[test]
+pub fn unsigned_tx_sees_aliased_sender() {
+    let (mut db, probe) = setup();
+    let sender = apply_l1_to_l2_alias(L1_SENDER);
+    let deposit = U256::from(1e18);
+    let value = U256::from(0.25e18);
+
+    ArbitrumTx::deposit(L1_SENDER, sender, deposit).execute(&mut db, U256::from(BASEFEE));
+
+    let receipt = ArbitrumTx::unsigned(L1_SENDER, probe, IHostioProbe::contextCall {}.abi_encode())
+        .value(value)
+        .gas_limit(1_000_000)
+        .gas_fee_cap(U256::from(BASEFEE * 2))
+        .execute(&mut db, U256::from(BASEFEE));
+    let result = receipt.result.unwrap();
+    assert!(result.is_success(), "{:?}", result);
+    let context = decode_context(result.output().unwrap());
+
+    assert_eq!(context.sender, sender);
+    assert_eq!(context.origin, sender);
+    assert_eq!(context.value, value);
+    assert_eq!(context.gas_price, U256::from(BASEFEE));
+
+    let account = db.load_account(sender).unwrap().info.clone();
+    assert_eq!(account.nonce, 1);
+    assert_eq!(
+        account.balance,
+        deposit - value - U256::from(result.gas_used() * BASEFEE)
+    );
+    assert_eq!(db.load_account(probe).unwrap().info.balance, value);
+}
+
+#[test]
+pub fn unsigned_tx_checks_nonce() {
+    let (mut db, probe) = setup();
+    let sender = apply_l1_to_l2_alias(L1_SENDER);
+
+    ArbitrumTx::deposit(L1_SENDER, sender, U256::from(1e18)).execute(&mut db, U256::from(BASEFEE));
+
+    let receipt = ArbitrumTx::unsigned(L1_SENDER, probe, IHostioProbe::contextCall {}.abi_encode())
+        .nonce(5)
+        .gas_limit(1_000_000)
+        .try_execute(&mut db, U256::from(BASEFEE));
+    match receipt {
+        Err(EVMError::Transaction(InvalidTransaction::NonceTooHigh { tx, state })) => {
+            assert_eq!((tx, state), (5, 0));
+        }
+        receipt => panic!("Expected NonceTooHigh: {:?}", receipt),
+    }
+}
+
+#[test]
+pub fn contract_tx_skips_nonce_check() {
+    let (mut db, probe) = setup();
+    let sender = apply_l1_to_l2_alias(L1_SENDER);
+
+    ArbitrumTx::deposit(L1_SENDER, sender, U256::from(1e18)).execute(&mut db, U256::from(BASEFEE));
+
+    let receipt = ArbitrumTx::contract(L1_SENDER, probe, IHostioProbe::contextCall {}.abi_encode())
+        .nonce(5)
+        .gas_limit(1_000_000)
+        .execute(&mut db, U256::from(BASEFEE));
+    let result = receipt.result.unwrap();
+    assert!(result.is_success(), "{:?}", result);
+    let context = decode_context(result.output().unwrap());
+
+    assert_eq!(context.sender, sender);
+    assert_eq!(context.origin, sender);
+    assert_eq!(context.value, U256::ZERO);
+}
+
+#[test]
+pub fn retryable_auto_redeem_executes_program() {
+    let (mut db, probe) = setup();
+    let sender = apply_l1_to_l2_alias(L1_SENDER);
+    let refund_address = address!("Bd770416a3345F91E4B34576cb804a576fa48EB2");
+
+    let gas_limit = 1_000_000;
+    let call_value = U256::from(0.25e18);
+    let submission_fee = U256::from(1e15);
+    let deposit = U256::from(1e18);
+
+    let submission = ArbitrumTx::submit_retryable(L1_SENDER, probe, IHostioProbe::contextCall {}.abi_encode())
+        .value(call_value)
+        .deposit_value(deposit)
+        .max_submission_fee(submission_fee)
+        .gas_limit(gas_limit)
+        .gas_fee_cap(U256::from(BASEFEE))
+        .fee_refund_address(refund_address);
+    let receipt = submission.execute(&mut db, U256::from(BASEFEE));
+
+    let redeem = receipt.redeem.unwrap();
+    assert!(redeem.is_success(), "{:?}", redeem);
+    let context = decode_context(redeem.output().unwrap());
+
+    assert_eq!(context.sender, sender);
+    assert_eq!(context.origin, sender);
+    assert_eq!(context.value, call_value);
+    assert_eq!(balance(&mut db, probe), call_value);
+
+    // The redeem spent the escrow and the gas it did not use is refunded along with the
+    // rest of the deposit, so the fee refund address pays only for gas used.
+    let ticket = receipt.ticket.unwrap();
+    assert_eq!(balance(&mut db, ticket.escrow_address()), U256::ZERO);
+    assert_eq!(balance(&mut db, sender), U256::ZERO);
+    assert_eq!(
+        balance(&mut db, refund_address),
+        deposit - submission_fee - call_value - U256::from(redeem.gas_used() * BASEFEE)
+    );
+    assert_eq!(ticket_timeout(&mut db, &ticket), None);
+}
+
+#[test]
+pub fn retryable_without_gas_waits_for_redeem() {
+    let (mut db, probe) = setup();
+    let beneficiary = address!("be0eb53f46cd790cd13851d5eff43d12404d33e8");
+    let call_value = U256::from(0.25e18);
+
+    let receipt = ArbitrumTx::submit_retryable(L1_SENDER, probe, IHostioProbe::contextCall {}.abi_encode())
+        .value(call_value)
+        .deposit_value(U256::from(1e18))
+        .beneficiary(beneficiary)
+        .execute(&mut db, U256::from(BASEFEE));
+
+    assert!(receipt.redeem.is_none());
+    let ticket = receipt.ticket.unwrap();
+
+    assert_eq!(ticket_timeout(&mut db, &ticket), Some(U256::from(ticket.timeout)));
+    let calldata = ArbRetryableTx::getBeneficiaryCall { ticketId: ticket.id };
+    let result = call(&mut db, ARB_RETRYABLE_TX, calldata.abi_encode());
+    assert!(result.is_success(), "{:?}", result);
+    let decoded =
+        ArbRetryableTx::getBeneficiaryCall::abi_decode_returns(result.output().unwrap(), true);
+    assert_eq!(decoded.unwrap()._0, beneficiary);
+
+    assert_eq!(balance(&mut db, ticket.escrow_address()), call_value);
+    assert_eq!(balance(&mut db, probe), U256::ZERO);
+}
+
+#[test]
+pub fn internal_tx_is_free_and_leaves_programs_untouched() {
+    let (mut db, probe) = setup();
+    let storage_before = db.load_account(probe).unwrap().storage.clone();
+
+    let receipt = ArbitrumTx::start_block(1, 1).execute(&mut db, U256::from(BASEFEE));
+
+    assert!(receipt.result.is_none());
+    assert_eq!(balance(&mut db, ARBOS_ADDRESS), U256::ZERO);
+    assert_eq!(balance(&mut db, probe), U256::ZERO);
+    assert_eq!(db.load_account(probe).unwrap().storage, storage_before);
+}
+
+#[test]
+pub fn start_block_reaps_expired_retryable() {
+    let (mut db, probe) = setup();
+    let beneficiary = address!("be0eb53f46cd790cd13851d5eff43d12404d33e8");
+    let call_value = U256::from(0.25e18);
+
+    let receipt = ArbitrumTx::submit_retryable(L1_SENDER, probe, IHostioProbe::contextCall {}.abi_encode())
+        .value(call_value)
+        .deposit_value(call_value)
+        .beneficiary(beneficiary)
+        .execute(&mut db, U256::from(BASEFEE));
+    let ticket = receipt.ticket.unwrap();
+
+    // A ticket lives until the end of its timeout second.
+    ArbitrumTx::start_block(1, RETRYABLE_LIFETIME_SECONDS).execute(&mut db, U256::ZERO);
+    assert_eq!(ticket_timeout(&mut db, &ticket), Some(U256::from(ticket.timeout)));
+    assert_eq!(balance(&mut db, ticket.escrow_address()), call_value);
+
+    ArbitrumTx::start_block(2, RETRYABLE_LIFETIME_SECONDS + 1).execute(&mut db, U256::ZERO);
+    assert_eq!(ticket_timeout(&mut db, &ticket), None);
+    assert_eq!(balance(&mut db, ticket.escrow_address()), U256::ZERO);
+    assert_eq!(balance(&mut db, beneficiary), call_value);
+    assert_eq!(balance(&mut db, probe), U256::ZERO);
+}
@@ -0,0 +1,45 @@
+use common::arbitrum::read_program;
+use common::fixtures::base_state;
+use common::DEPLOYER;
+use revm::primitives::{keccak256, U256};
+
+mod common;
+
+#[test]
+pub fn base_state_has_every_fixture_deployed() {
+    let (db, fixtures) = base_state();
+
+    for (name, address) in fixtures.all() {
+        let account = db.accounts.get(&address).unwrap();
+        assert!(account.info.code.as_ref().is_some_and(|code| !code.is_empty()), "{}", name);
+    }
+
+    // DEPLOYER sent one CREATE per fixture and nothing else.
+    let deployments = fixtures.all().len() as u64;
+    assert_eq!(db.accounts.get(&DEPLOYER).unwrap().info.nonce, deployments);
+}
+
+#[test]
+pub fn base_state_has_every_stylus_fixture_activated() {
+    let (mut db, fixtures) = base_state();
+
+    // Stylus programs come first.
+    for (name, address) in &fixtures.all()[..7] {
+        let code_hash = db.accounts.get(address).unwrap().info.code_hash;
+        assert!(read_program(&mut db, code_hash).is_some(), "{}", name);
+    }
+}
+
+#[test]
+pub fn base_state_copies_are_independent() {
+    let (mut db, fixtures) = base_state();
+    let slot = U256::from_be_bytes(keccak256("base-state-slot").0);
+
+    db.load_account(fixtures.test_program).unwrap().storage.insert(slot, U256::from(1));
+    db.load_account(DEPLOYER).unwrap().info.balance = U256::ZERO;
+
+    let (mut fresh, same_fixtures) = base_state();
+    assert_eq!(same_fixtures.test_program, fixtures.test_program);
+    assert!(fresh.load_account(fixtures.test_program).unwrap().storage.get(&slot).is_none());
+    assert_eq!(fresh.load_account(DEPLOYER).unwrap().info.balance, U256::from(1e18));
+}
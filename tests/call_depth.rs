@@ -0,0 +1,194 @@
+use alloy_sol_types::{sol, SolCall};
+use common::fixtures::base_state;
+use common::DEPLOYER;
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{Address, ExecutionResult, HaltReason, TxEnv, TxKind, U256};
+
+mod common;
+
+/// Frames a transaction may nest below its top-level call.
+const CALL_DEPTH_LIMIT: u64 = 1024;
+
+/// Enough gas for 1024 nested calls to survive the 63/64 rule.
+const DEEP_GAS_LIMIT: u64 = 1_000_000_000_000;
+
+sol!("tests/assets/abi/recursive.sol");
+
+struct Setup {
+    db: CacheDB<EmptyDB>,
+    stylus: Address,
+    evm: Address,
+}
+
+impl Setup {
+    fn new() -> Self {
+        let (db, fixtures) = base_state();
+
+        Self {
+            db,
+            stylus: fixtures.recursive,
+            evm: fixtures.recursive_evm,
+        }
+    }
+
+    /// The other half of the Stylus/EVM pair.
+    fn other(&self, contract: Address) -> Address {
+        if contract == self.stylus {
+            self.evm
+        } else {
+            self.stylus
+        }
+    }
+
+    fn transact(&mut self, to: Address, data: Vec<u8>, gas_limit: u64) -> ExecutionResult {
+        let evm = revm::Evm::builder()
+            .with_db(&mut self.db)
+            .modify_tx_env(|tx: &mut TxEnv| {
+                tx.caller = DEPLOYER;
+                tx.transact_to = TxKind::Call(to);
+                tx.data = data.into();
+                tx.gas_limit = gas_limit;
+            });
+
+        evm.build().transact_commit().unwrap()
+    }
+
+    /// Has `from` bounce `depth` calls back and forth with its counterpart.
+    fn recurse(&mut self, from: Address, depth: u64) -> ExecutionResult {
+        let calldata = IRecursive::recurseCall {
+            other: self.other(from),
+            depth: U256::from(depth),
+        };
+        self.transact(from, calldata.abi_encode(), DEEP_GAS_LIMIT)
+    }
+
+    /// Has `caller` make `data` to `target` through `tryCall`, returning the call's outcome.
+    fn try_call(&mut self, caller: Address, target: Address, data: Vec<u8>, gas_limit: u64) -> (bool, Vec<u8>) {
+        let calldata = IRecursive::tryCallCall {
+            target,
+            data: data.into(),
+        };
+
+        let result = self.transact(caller, calldata.abi_encode(), gas_limit);
+        assert!(result.is_success(), "{:?}", result);
+
+        let returned = IRecursive::tryCallCall::abi_decode_returns(result.output().unwrap(), true).unwrap();
+        (returned._0, returned._1.to_vec())
+    }
+}
+
+fn recursion_depth(result: &ExecutionResult) -> u64 {
+    assert!(result.is_success(), "{:?}", result);
+    IRecursive::recurseCall::abi_decode_returns(result.output().unwrap(), true)
+        .unwrap()
+        ._0
+        .to::<u64>()
+}
+
+#[test]
+pub fn alternating_recursion_reaches_depth_limit() {
+    let mut setup = Setup::new();
+
+    for from in [setup.stylus, setup.evm] {
+        let result = setup.recurse(from, CALL_DEPTH_LIMIT);
+
+        assert_eq!(recursion_depth(&result), CALL_DEPTH_LIMIT);
+    }
+}
+
+#[test]
+pub fn alternating_recursion_past_depth_limit_reverts() {
+    let mut setup = Setup::new();
+
+    for from in [setup.stylus, setup.evm] {
+        // The call that would nest too deep fails without data, and every frame above
+        // bubbles that failure up.
+        match setup.recurse(from, CALL_DEPTH_LIMIT + 1) {
+            ExecutionResult::Revert { output, .. } => assert!(output.is_empty()),
+            result => panic!("Expected revert: {:?}", result),
+        }
+    }
+}
+
+#[test]
+pub fn caller_recovers_from_too_deep_call() {
+    let mut setup = Setup::new();
+
+    for caller in [setup.stylus, setup.evm] {
+        for target in [setup.stylus, setup.evm] {
+            // tryCall takes the top frame, leaving the recursion one level less.
+            let other = setup.other(target);
+            let recurse = |depth: u64| {
+                IRecursive::recurseCall {
+                    other,
+                    depth: U256::from(depth),
+                }
+                .abi_encode()
+            };
+
+            let (success, output) = setup.try_call(caller, target, recurse(CALL_DEPTH_LIMIT - 1), DEEP_GAS_LIMIT);
+            assert!(success);
+            assert_eq!(output, U256::from(CALL_DEPTH_LIMIT - 1).to_be_bytes_vec());
+
+            let (success, output) = setup.try_call(caller, target, recurse(CALL_DEPTH_LIMIT), DEEP_GAS_LIMIT);
+            assert!(!success);
+            assert!(output.is_empty());
+        }
+    }
+}
+
+#[test]
+pub fn stylus_recursion_within_stack_limit() {
+    let mut setup = Setup::new();
+
+    let calldata = IRecursive::recurseInternalCall { depth: 1000 };
+    let result = setup.transact(setup.stylus, calldata.abi_encode(), 1_000_000_000);
+    assert!(result.is_success(), "{:?}", result);
+
+    let depth = IRecursive::recurseInternalCall::abi_decode_returns(result.output().unwrap(), true)
+        .unwrap()
+        ._0;
+    assert_eq!(depth, 1000);
+}
+
+#[test]
+pub fn stylus_recursion_exhausts_stack() {
+    let mut setup = Setup::new();
+    let gas_limit = 1_000_000_000;
+
+    // Nitro's `max_stack_depth` is counted in WASM stack words, so a million frames is well
+    // beyond it. Running out of stack traps the program and consumes all gas. Nitro reports
+    // the trap as `vm.ErrDepth`, which is revm's `CallTooDeep`.
+    let calldata = IRecursive::recurseInternalCall { depth: 1_000_000 };
+    match setup.transact(setup.stylus, calldata.abi_encode(), gas_limit) {
+        ExecutionResult::Halt { reason, gas_used } => {
+            assert_eq!(reason, HaltReason::CallTooDeep);
+            assert_eq!(gas_used, gas_limit);
+        }
+        result => panic!("Expected halt: {:?}", result),
+    }
+}
+
+#[test]
+pub fn evm_recursion_overflows_stack() {
+    let mut setup = Setup::new();
+
+    let calldata = IRecursive::recurseInternalCall { depth: 1_000_000 };
+    match setup.transact(setup.evm, calldata.abi_encode(), 1_000_000_000) {
+        ExecutionResult::Halt { reason, .. } => assert_eq!(reason, HaltReason::StackOverflow),
+        result => panic!("Expected halt: {:?}", result),
+    }
+}
+
+#[test]
+pub fn caller_recovers_from_stack_overflow() {
+    let mut setup = Setup::new();
+    let calldata = IRecursive::recurseInternalCall { depth: 1_000_000 }.abi_encode();
+
+    for caller in [setup.stylus, setup.evm] {
+        let (success, output) = setup.try_call(caller, setup.stylus, calldata.clone(), 1_000_000_000);
+
+        assert!(!success);
+        assert!(output.is_empty());
+    }
+}
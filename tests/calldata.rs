@@ -0,0 +1,112 @@
+use alloy_sol_types::{sol, SolCall};
+use arbos_revm_tests::calldata::{decode_output, encode};
+use revm::primitives::{hex, keccak256, U256};
+
+sol!("tests/assets/abi/test_program.sol");
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+pub fn raw_calldata_is_passed_through() {
+    let (data, function) = encode(Some("0xdeadbeef"), None, &[]).unwrap();
+    assert_eq!(data.as_ref(), &[0xde, 0xad, 0xbe, 0xef]);
+    assert!(function.is_none());
+
+    let (data, function) = encode(None, None, &[]).unwrap();
+    assert!(data.is_empty());
+    assert!(function.is_none());
+
+    let error = encode(Some("0xzz"), None, &[]).unwrap_err();
+    assert!(error.starts_with("Invalid calldata: "), "{}", error);
+}
+
+#[test]
+pub fn signature_encodes_like_sol() {
+    let call = ITestProgram::setStorageCall {
+        key: keccak256("calldata-key"),
+        value: keccak256("calldata-value"),
+    };
+
+    let (data, function) = encode(
+        None,
+        Some("setStorage(bytes32,bytes32)"),
+        &args(&[&call.key.to_string(), &call.value.to_string()]),
+    )
+    .unwrap();
+    assert_eq!(data.to_vec(), call.abi_encode());
+    assert_eq!(function.unwrap().name, "setStorage");
+}
+
+#[test]
+pub fn signature_arguments_are_checked() {
+    let error = encode(None, Some("setStorage(bytes32,bytes32)"), &args(&["0x01"])).unwrap_err();
+    assert_eq!(error, "setStorage takes 2 arguments, got 1");
+
+    let error = encode(None, Some("transfer(address,uint256)"), &args(&["0x01", "1"])).unwrap_err();
+    assert!(error.starts_with("Invalid address argument 0x01: "), "{}", error);
+
+    let error = encode(None, Some("not a signature"), &[]).unwrap_err();
+    assert!(error.starts_with("Invalid signature not a signature: "), "{}", error);
+}
+
+#[test]
+pub fn output_is_decoded_with_return_types() {
+    let key = keccak256("calldata-key");
+    let (_, function) =
+        encode(None, Some("getStorage(bytes32) returns (bytes32)"), &args(&[&key.to_string()]))
+            .unwrap();
+    let function = function.unwrap();
+
+    let output = ITestProgram::getStorageCall::abi_encode_returns(&(key,));
+    assert_eq!(decode_output(&function, &output), Some(vec![key.to_string()]));
+
+    // Output that doesn't fit the return types isn't decoded.
+    assert_eq!(decode_output(&function, &[0x01]), None);
+}
+
+#[test]
+pub fn output_values_are_formatted() {
+    let (_, function) = encode(
+        None,
+        Some("f() returns (uint256[],bool,int8,string,bytes,(address,bytes4))"),
+        &[],
+    )
+    .unwrap();
+    let function = function.unwrap();
+
+    let output = hex::decode(concat!(
+        "00000000000000000000000000000000000000000000000000000000000000e0",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        "0000000000000000000000000000000000000000000000000000000000000140",
+        "0000000000000000000000000000000000000000000000000000000000000180",
+        "000000000000000000000000bd770416a3345f91e4b34576cb804a576fa48eb1",
+        "deadbeef00000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "6869000000000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "ff00000000000000000000000000000000000000000000000000000000000000",
+    ))
+    .unwrap();
+
+    assert_eq!(
+        decode_output(&function, &output),
+        Some(vec![
+            "[1, 2]".to_string(),
+            "true".to_string(),
+            "-1".to_string(),
+            "\"hi\"".to_string(),
+            "0xff".to_string(),
+            "(0xBd770416a3345F91E4B34576cb804a576fa48EB1, 0xdeadbeef)".to_string(),
+        ])
+    );
+
+    // A function without return types has nothing to decode.
+    let (_, function) = encode(None, Some("f()"), &[]).unwrap();
+    assert_eq!(decode_output(&function.unwrap(), &U256::from(1).to_be_bytes_vec()), None);
+}
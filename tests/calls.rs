@@ -1,39 +1,12 @@
 use alloy_sol_macro::sol;
+use common::multicall::Multicaller;
 use revm::db::{CacheDB, EmptyDB};
-use revm::primitives::{address, hex, keccak256, Address, TxEnv, TxKind, U256};
+use revm::primitives::{keccak256, Address, TxEnv, TxKind, U256};
 use serde_json::Value;
 
 mod common;
 
-// Constants
-const MULTICALL_BYTECODE: &[u8] = include_bytes!("assets/multicall.wasm");
-const TEST_PROGRAM_BYTECODE: &[u8] = include_bytes!("assets/test_program.wasm");
-const MULTICALL_EVM_BYTECODE: &str = include_str!("assets/Multicaller.bin");
-
-sol!{
-    contract Multicaller {
-        enum CallType {
-            CALL,
-            DELEGATECALL,
-            STATICCALL
-        }
-
-        struct Call {
-            CallType callType;
-            address target;
-            bytes data;
-            uint256 value;
-            uint256 gas_limit;
-        }
-
-        function multicall(Call[] memory calls) external payable returns (bytes[] memory results);
-    }
-
-    contract TestProgram {
-        function setStorage(bytes32 slot, bytes32 data) external;
-        function getStorage(bytes32 slot) external view returns (bytes32);
-    }
-}
+sol!("tests/assets/abi/test_program.sol");
 
 // Helper struct for test environment
 struct TestSetup {
@@ -46,12 +19,8 @@ struct TestSetup {
 
 impl TestSetup {
     fn new() -> Self {
-        let mut db = CacheDB::new(EmptyDB::new());
-        let deployer = address!("Bd770416a3345F91E4B34576cb804a576fa48EB1");
-
-        // Deploy contracts
-        let multicall = common::deploy_wasm(&mut db, MULTICALL_BYTECODE.to_vec(), deployer);
-        let storage = common::deploy_wasm(&mut db, TEST_PROGRAM_BYTECODE.to_vec(), deployer);
+        let (mut db, fixtures) = common::fixtures::base_state();
+        let storage = fixtures.test_program;
 
         // Initialize storage
         let slot = keccak256("some-storage-slot");
@@ -61,17 +30,11 @@ impl TestSetup {
             .storage
             .insert(slot.into(), value.into());
 
-        // Deploy EVM version
-    
-        let bytecode = hex::decode(MULTICALL_EVM_BYTECODE).unwrap();
-
-        let multicall_evm = common::deploy_solidity(&mut db, bytecode, deployer);
-
         Self {
-            deployer,
-            multicall,
+            deployer: common::DEPLOYER,
+            multicall: fixtures.multicall,
             storage,
-            multicall_evm,
+            multicall_evm: fixtures.multicall_evm,
             db,
         }
     }
@@ -117,8 +80,8 @@ mod tests {
     fn test_direct_storage_read() {
         let mut setup = TestSetup::new();
 
-        let calldata = TestProgram::getStorageCall {
-            slot: keccak256("some-storage-slot").into(),
+        let calldata = ITestProgram::getStorageCall {
+            key: keccak256("some-storage-slot").into(),
         };
 
         let result = setup.execute(setup.storage, calldata.abi_encode().into());
@@ -134,8 +97,8 @@ mod tests {
     fn test_multicall_storage_read() {
         let mut setup = TestSetup::new();
 
-        let storage_call = TestProgram::getStorageCall {
-            slot: keccak256("some-storage-slot").into(),
+        let storage_call = ITestProgram::getStorageCall {
+            key: keccak256("some-storage-slot").into(),
         };
 
         let forward_call = Multicaller::multicallCall {
@@ -163,9 +126,9 @@ mod tests {
     fn test_multicall_storage_write() {
         let mut setup = TestSetup::new();
 
-        let storage_call = TestProgram::setStorageCall {
-            slot: keccak256("some-storage-slot").into(),
-            data: keccak256("new-storage-value").into(),
+        let storage_call = ITestProgram::setStorageCall {
+            key: keccak256("some-storage-slot").into(),
+            value: keccak256("new-storage-value").into(),
         };
         
         let forward_call = Multicaller::multicallCall {
@@ -199,9 +162,9 @@ mod tests {
     fn test_static_call_write_protection() {
         let mut setup = TestSetup::new();
 
-        let storage_call = TestProgram::setStorageCall {
-            slot: keccak256("some-storage-slot").into(),
-            data: keccak256("new-storage-value").into(),
+        let storage_call = ITestProgram::setStorageCall {
+            key: keccak256("some-storage-slot").into(),
+            value: keccak256("new-storage-value").into(),
         };
         
 
@@ -234,8 +197,8 @@ mod tests {
             .storage
             .insert(slot.into(), keccak256("multicall-storage-value").into());
 
-        let storage_call = TestProgram::getStorageCall {
-            slot: keccak256("some-storage-slot").into(),
+        let storage_call = ITestProgram::getStorageCall {
+            key: keccak256("some-storage-slot").into(),
         };
 
         let forward_call = Multicaller::multicallCall {
@@ -267,9 +230,9 @@ mod tests {
         let slot = keccak256("some-storage-slot");
         let data = keccak256("new-storage-value");
 
-        let storage_call = TestProgram::setStorageCall {
-            slot,
-            data,
+        let storage_call = ITestProgram::setStorageCall {
+            key: slot,
+            value: data,
         };
 
         let forward_call = Multicaller::multicallCall {
@@ -305,8 +268,8 @@ mod tests {
         let slot = keccak256("some-storage-slot");
         let data = keccak256("new-storage-value");
 
-        let get_storage_call = TestProgram::getStorageCall {
-            slot,
+        let get_storage_call = ITestProgram::getStorageCall {
+            key: slot,
         };
 
         let forward_call = Multicaller::multicallCall {
@@ -319,9 +282,9 @@ mod tests {
             }],
         };
         
-        let set_storage_call = TestProgram::setStorageCall {
-            slot,
-            data,
+        let set_storage_call = ITestProgram::setStorageCall {
+            key: slot,
+            value: data,
         };
 
         let forward_call = Multicaller::multicallCall {
@@ -362,9 +325,9 @@ mod tests {
         let slot = keccak256("some-storage-slot");
         let data = keccak256("new-storage-value");
 
-        let storage_call = TestProgram::setStorageCall {
-            slot,
-            data,
+        let storage_call = ITestProgram::setStorageCall {
+            key: slot,
+            value: data,
         };
 
         let forward_call = Multicaller::multicallCall {
@@ -0,0 +1,293 @@
+use std::convert::Infallible;
+
+use alloy_sol_types::{sol, SolCall};
+use common::{
+    activation, compress_wasm, configure, deploy_wasm, setup_simple_test, wasm_contract_init_code,
+    DEPLOYER, MAX_WASM_SIZE, STYLUS_MAX_CODE_SIZE, STYLUS_MAX_INITCODE_SIZE,
+};
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{
+    AccountInfo, Address, Bytecode, EVMError, ExecutionResult, HaltReason, InvalidTransaction,
+    TxEnv, TxKind, U256,
+};
+use revm::STYLUS_MAGIC_BYTES;
+
+mod common;
+
+const CREATE_PROGRAM_BYTECODE: &[u8] = include_bytes!("assets/create_program.wasm");
+const CONTROL_WAT: &str = include_str!("fixtures/invalid-wasm/control.wat");
+
+sol!("tests/assets/abi/create_program.sol");
+
+/// How a program gets deployed: by a CREATE transaction or by a Stylus factory's `deploy`.
+#[derive(Clone, Copy, Debug)]
+enum Creator {
+    Transaction,
+    StylusFactory,
+}
+
+struct Creation {
+    /// The created program, if creation succeeded.
+    address: Option<Address>,
+    result: ExecutionResult,
+}
+
+fn leb128_len(mut value: usize) -> usize {
+    let mut len = 1;
+    while value >= 0x80 {
+        value >>= 7;
+        len += 1;
+    }
+    len
+}
+
+fn leb128(mut value: usize) -> Vec<u8> {
+    let mut bytes = vec![];
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+/// The control program, padded with a custom section to exactly `len` bytes of WASM.
+fn wasm_of_len(len: usize) -> Vec<u8> {
+    let mut wasm = wat::parse_str(CONTROL_WAT).unwrap();
+
+    // A custom section is its id, the LEB128 content length, then a one byte name and payload.
+    let remaining = len - wasm.len() - 1;
+    let content_len = (1..=5)
+        .map(|leb_len| remaining - leb_len)
+        .find(|content_len| leb128_len(*content_len) + content_len == remaining)
+        .expect("no custom section pads to the requested length");
+
+    wasm.push(0);
+    wasm.extend(leb128(content_len));
+    wasm.extend([1, b'p']);
+    wasm.resize(len, 0);
+    wasm
+}
+
+/// A program whose deployed code, Stylus prefix included, is exactly `len` bytes.
+fn program_of_code_len(len: usize) -> Vec<u8> {
+    wasm_of_len(len - STYLUS_MAGIC_BYTES.len())
+}
+
+/// Stores `wasm` as a program the way a prestate would, without a deployment to apply the
+/// code size limit.
+fn install_program(db: &mut CacheDB<EmptyDB>, wasm: Vec<u8>) -> Address {
+    let program = Address::repeat_byte(0x57);
+    let mut code = STYLUS_MAGIC_BYTES.to_vec();
+    code.extend(wasm);
+    db.insert_account_info(program, AccountInfo::from_bytecode(Bytecode::new_raw(code.into())));
+    program
+}
+
+fn transact(
+    db: &mut CacheDB<EmptyDB>,
+    to: TxKind,
+    data: Vec<u8>,
+) -> Result<ExecutionResult, EVMError<Infallible>> {
+    let evm = revm::Evm::builder()
+        .with_db(db)
+        .modify_tx_env(|tx: &mut TxEnv| {
+            tx.caller = DEPLOYER;
+            tx.transact_to = to;
+            tx.data = data.into();
+            tx.gas_limit = 1_000_000_000;
+        })
+        .modify_cfg_env(configure);
+
+    evm.build().transact_commit()
+}
+
+impl Creator {
+    fn create(self, init_code: Vec<u8>) -> (CacheDB<EmptyDB>, Creation) {
+        let mut db = CacheDB::new(EmptyDB::new());
+        setup_simple_test(&mut db);
+
+        let creation = match self {
+            Creator::Transaction => {
+                let expected_address = DEPLOYER.create(0);
+                let result = transact(&mut db, TxKind::Create, init_code).unwrap();
+
+                Creation {
+                    address: result.is_success().then_some(expected_address),
+                    result,
+                }
+            }
+            Creator::StylusFactory => {
+                let factory = deploy_wasm(&mut db, CREATE_PROGRAM_BYTECODE.to_vec(), DEPLOYER);
+                let calldata = ICreateProgram::createCall {
+                    init_code: init_code.into(),
+                    endowment: U256::ZERO,
+                };
+                let result =
+                    transact(&mut db, TxKind::Call(factory), calldata.abi_encode()).unwrap();
+
+                let address = result.is_success().then(|| {
+                    ICreateProgram::createCall::abi_decode_returns(result.output().unwrap(), true)
+                        .unwrap()
+                        ._0
+                });
+                Creation { address, result }
+            }
+        };
+
+        (db, creation)
+    }
+}
+
+fn call(db: &mut CacheDB<EmptyDB>, program: Address) -> ExecutionResult {
+    transact(db, TxKind::Call(program), vec![]).unwrap()
+}
+
+fn code_len(db: &CacheDB<EmptyDB>, address: Address) -> usize {
+    db.accounts
+        .get(&address)
+        .and_then(|account| account.info.code.as_ref())
+        .map(|code| code.original_bytes().len())
+        .unwrap_or_default()
+}
+
+#[test]
+pub fn code_size_at_limit_deploys() {
+    for creator in [Creator::Transaction, Creator::StylusFactory] {
+        let wasm = program_of_code_len(STYLUS_MAX_CODE_SIZE);
+        let (mut db, creation) = creator.create(wasm_contract_init_code(wasm));
+
+        let program = creation
+            .address
+            .unwrap_or_else(|| panic!("{:?}: {:?}", creator, creation.result));
+        assert_eq!(code_len(&db, program), STYLUS_MAX_CODE_SIZE);
+        assert!(call(&mut db, program).is_success());
+    }
+}
+
+/// Asserts that neither creator can deploy `init_code` because its code is over the limit.
+fn assert_code_size_rejected(init_code: Vec<u8>) {
+    let (_, creation) = Creator::Transaction.create(init_code.clone());
+    match creation.result {
+        ExecutionResult::Halt { reason, .. } => {
+            assert_eq!(reason, HaltReason::CreateContractSizeLimit);
+        }
+        result => panic!("Expected halt: {:?}", result),
+    }
+
+    // The failed child creation surfaces to the factory as empty revert data.
+    let (_, creation) = Creator::StylusFactory.create(init_code);
+    match creation.result {
+        ExecutionResult::Revert { output, .. } => assert!(output.is_empty()),
+        result => panic!("Expected revert: {:?}", result),
+    }
+}
+
+#[test]
+pub fn code_size_over_limit_is_rejected() {
+    let wasm = program_of_code_len(STYLUS_MAX_CODE_SIZE + 1);
+    assert_code_size_rejected(wasm_contract_init_code(wasm));
+}
+
+// The code size limit applies to the stored code and the WASM size limit to the WASM at
+// activation. Uncompressed, the harness's code size limit sits below MAX_WASM_SIZE, so the
+// code size limit stops the deployment before the WASM size matters.
+#[test]
+pub fn code_size_limit_applies_before_wasm_size() {
+    assert!(STYLUS_MAX_CODE_SIZE < MAX_WASM_SIZE);
+    assert_code_size_rejected(wasm_contract_init_code(wasm_of_len(MAX_WASM_SIZE)));
+}
+
+#[test]
+pub fn compressed_wasm_over_limit_fails_activation() {
+    // Compressed, the program is well under the code size limit but decompresses to more
+    // WASM than activation allows.
+    let wasm = wasm_of_len(MAX_WASM_SIZE + 1);
+    let code = compress_wasm(&wasm);
+    assert!(STYLUS_MAGIC_BYTES.len() + code.len() <= STYLUS_MAX_CODE_SIZE);
+
+    for creator in [Creator::Transaction, Creator::StylusFactory] {
+        let (mut db, creation) = creator.create(wasm_contract_init_code(code.clone()));
+        let program = creation
+            .address
+            .unwrap_or_else(|| panic!("{:?}: {:?}", creator, creation.result));
+
+        let result = call(&mut db, program);
+        assert_eq!(
+            activation::error(&result),
+            Some(activation::wasm_too_large(MAX_WASM_SIZE + 1).as_str()),
+            "{:?}: {:?}",
+            creator,
+            result
+        );
+    }
+}
+
+#[test]
+pub fn wasm_size_at_limit_activates() {
+    let mut db = CacheDB::new(EmptyDB::new());
+    setup_simple_test(&mut db);
+
+    let program = install_program(&mut db, wasm_of_len(MAX_WASM_SIZE));
+    let result = call(&mut db, program);
+    assert!(result.is_success(), "{:?}", result);
+}
+
+#[test]
+pub fn wasm_size_over_limit_fails_activation() {
+    let mut db = CacheDB::new(EmptyDB::new());
+    setup_simple_test(&mut db);
+
+    let program = install_program(&mut db, wasm_of_len(MAX_WASM_SIZE + 1));
+    let result = call(&mut db, program);
+    assert_eq!(
+        activation::error(&result),
+        Some(activation::wasm_too_large(MAX_WASM_SIZE + 1).as_str()),
+        "{:?}",
+        result
+    );
+}
+
+/// Init code that deploys the control program, padded with trailing bytes the constructor
+/// never copies.
+fn init_code_of_len(len: usize) -> Vec<u8> {
+    let mut init_code = wasm_contract_init_code(wat::parse_str(CONTROL_WAT).unwrap());
+    init_code.resize(len, 0);
+    init_code
+}
+
+#[test]
+pub fn init_code_at_limit_deploys() {
+    for creator in [Creator::Transaction, Creator::StylusFactory] {
+        let (_, creation) = creator.create(init_code_of_len(STYLUS_MAX_INITCODE_SIZE));
+
+        assert!(creation.address.is_some(), "{:?}: {:?}", creator, creation.result);
+    }
+}
+
+#[test]
+pub fn init_code_over_limit_is_rejected() {
+    let mut db = CacheDB::new(EmptyDB::new());
+    setup_simple_test(&mut db);
+
+    let result = transact(&mut db, TxKind::Create, init_code_of_len(STYLUS_MAX_INITCODE_SIZE + 1));
+    match result {
+        Err(EVMError::Transaction(InvalidTransaction::CreateInitCodeSizeLimit)) => {}
+        result => panic!("Expected CreateInitCodeSizeLimit: {:?}", result),
+    }
+
+    // Inside a program the limit fails the `deploy` hostio like the CREATE opcode, halting
+    // the factory.
+    let (_, creation) =
+        Creator::StylusFactory.create(init_code_of_len(STYLUS_MAX_INITCODE_SIZE + 1));
+    assert!(creation.address.is_none());
+    match creation.result {
+        ExecutionResult::Halt { reason, .. } => {
+            assert_eq!(reason, HaltReason::CreateInitCodeSizeLimit);
+        }
+        result => panic!("Expected halt: {:?}", result),
+    }
+}
@@ -0,0 +1,40 @@
+//! How a failed activation surfaces. Nitro activates a program on its first call, and
+//! arbos-revm reverts that call with Nitro's activation error as UTF-8 output, its causes
+//! joined by `": "` as eyre's alternate `Display` prints them. Nitro quotes names with
+//! [`Color::red`], so the expected messages do too.
+
+use std::fmt::Display;
+
+use arbutil::Color;
+use revm::primitives::ExecutionResult;
+
+use super::MAX_WASM_SIZE;
+
+/// The activation error `result` reverted with, or `None` if it isn't a UTF-8 revert.
+pub(crate) fn error(result: &ExecutionResult) -> Option<&str> {
+    match result {
+        ExecutionResult::Revert { output, .. } => std::str::from_utf8(output).ok(),
+        _ => None,
+    }
+}
+
+/// Activation error for a WASM larger than [`MAX_WASM_SIZE`], checked before parsing. A
+/// compressed program is measured after decompression.
+pub(crate) fn wasm_too_large(len: usize) -> String {
+    format!("wasm too large: {} > {}", len, MAX_WASM_SIZE)
+}
+
+/// Activation error for a WASM that failed one of Nitro's checks while parsing.
+pub(crate) fn parse_error(cause: impl Display) -> String {
+    format!("failed to parse wasm: {}", cause)
+}
+
+/// Activation error for a WASM wasmparser rejected; `cause` is wasmparser's message.
+pub(crate) fn validation_error(cause: impl Display) -> String {
+    parse_error(format!("failed to validate {}: {}", "user".red(), cause))
+}
+
+/// Activation error for a WASM that parsed but couldn't be built into a module.
+pub(crate) fn build_error(cause: impl Display) -> String {
+    format!("failed to build user module: {}", cause)
+}
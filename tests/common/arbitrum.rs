@@ -0,0 +1,789 @@
+//! Builders for Arbitrum-specific transaction types.
+//!
+//! arbos-revm only receives plain `TxEnv`s, so each builder reproduces what Nitro's
+//! transaction processor does around the EVM for its tx type: minting L1 deposits,
+//! aliasing L1 senders, escrowing retryable call value, scheduling auto-redeems and
+//! reaping expired tickets at the start of a block.
+//! Retryable tickets are also written into ArbOS state so `ArbRetryableTx` can see them.
+
+use std::fmt::Debug;
+
+use alloy_rlp::{Encodable, Header};
+use alloy_sol_types::{sol, SolCall, SolEvent, SolEventInterface};
+use revm::{
+    db::{AccountState, CacheDB},
+    primitives::{
+        address, keccak256, Address, BlockEnv, Bytes, CfgEnv, EVMError, ExecutionResult, Log,
+        TxEnv, TxKind, B256, U256,
+    },
+    Database, DatabaseRef,
+};
+
+/// Sender and recipient of ArbOS internal transactions.
+pub(crate) const ARBOS_ADDRESS: Address = address!("00000000000000000000000000000000000A4B05");
+
+/// Account whose storage holds ArbOS state.
+pub(crate) const ARBOS_STATE_ADDRESS: Address =
+    address!("A4B05FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF");
+
+pub(crate) const ARB_SYS: Address = address!("0000000000000000000000000000000000000064");
+pub(crate) const ARB_RETRYABLE_TX: Address = address!("000000000000000000000000000000000000006E");
+
+pub(crate) const RETRYABLE_LIFETIME_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// Offset Nitro adds to L1 contract addresses when their messages reach L2.
+pub(crate) const L1_TO_L2_ALIAS_OFFSET: Address =
+    address!("1111000000000000000000000000000000001111");
+
+const DEFAULT_GAS_LIMIT: u64 = 1_000_000_000;
+
+/// EIP-2718 type byte of Nitro's `ArbitrumRetryTx`.
+const ARBITRUM_RETRY_TX_TYPE: u8 = 0x68;
+
+/// Timestamp of revm's default block, which every harness transaction executes in.
+const DEFAULT_TIMESTAMP: u64 = 1;
+
+sol! {
+    interface ArbSys {
+        function arbChainID() external view returns (uint256);
+        function isTopLevelCall() external view returns (bool);
+        function wasMyCallersAddressAliased() external view returns (bool);
+        function myCallersAddressWithoutAliasing() external view returns (address);
+    }
+
+    interface ArbRetryableTx {
+        function redeem(bytes32 ticketId) external returns (bytes32);
+        function getLifetime() external view returns (uint256);
+        function getTimeout(bytes32 ticketId) external view returns (uint256);
+        function keepalive(bytes32 ticketId) external returns (uint256);
+        function getBeneficiary(bytes32 ticketId) external view returns (address);
+        function cancel(bytes32 ticketId) external;
+        function getCurrentRedeemer() external view returns (address);
+
+        event TicketCreated(bytes32 indexed ticketId);
+        event LifetimeExtended(bytes32 indexed ticketId, uint256 newTimeout);
+        event RedeemScheduled(
+            bytes32 indexed ticketId,
+            bytes32 indexed retryTxHash,
+            uint64 indexed sequenceNum,
+            uint64 donatedGas,
+            address gasDonor,
+            uint256 maxRefund,
+            uint256 submissionFeeRefund
+        );
+        event Canceled(bytes32 indexed ticketId);
+
+        error NoTicketWithID();
+        error NotCallable();
+    }
+
+    interface ArbosActs {
+        function startBlock(
+            uint256 l1BaseFee,
+            uint64 l1BlockNumber,
+            uint64 l2BlockNumber,
+            uint64 timePassed
+        ) external;
+    }
+}
+
+/// Decodes the `ArbRetryableTx` events among `logs`, in emission order.
+pub(crate) fn retryable_events(logs: &[Log]) -> Vec<ArbRetryableTx::ArbRetryableTxEvents> {
+    logs.iter()
+        .filter(|log| log.address == ARB_RETRYABLE_TX)
+        .map(|log| {
+            ArbRetryableTx::ArbRetryableTxEvents::decode_raw_log(log.topics(), &log.data.data, true)
+                .unwrap()
+        })
+        .collect()
+}
+
+/// Maps an L1 sender to the address L2 code observes for its messages.
+///
+/// Addresses are 160-bit, so the offset wraps around.
+pub(crate) fn apply_l1_to_l2_alias(l1_address: Address) -> Address {
+    let aliased = U256::from_be_slice(l1_address.as_slice())
+        + U256::from_be_slice(L1_TO_L2_ALIAS_OFFSET.as_slice());
+    Address::from_word(aliased.into())
+}
+
+/// Inverse of [`apply_l1_to_l2_alias`].
+pub(crate) fn undo_l1_to_l2_alias(l2_address: Address) -> Address {
+    let unaliased = (U256::from(1) << 160) + U256::from_be_slice(l2_address.as_slice())
+        - U256::from_be_slice(L1_TO_L2_ALIAS_OFFSET.as_slice());
+    Address::from_word(unaliased.into())
+}
+
+/// The Arbitrum tx types the harness can execute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ArbitrumTxType {
+    /// `ArbitrumDepositTx`: mints ETH to the sender and forwards it to `to` without running code.
+    Deposit,
+    /// `ArbitrumUnsignedTx`: an L1-funded call from the aliased sender, nonce checked.
+    Unsigned,
+    /// `ArbitrumContractTx`: as `Unsigned`, but the sender nonce is not checked.
+    Contract,
+    /// `ArbitrumSubmitRetryableTx`: creates a ticket and, given gas, auto-redeems it.
+    SubmitRetryable,
+    /// `ArbitrumInternalTx`: ArbOS bookkeeping sent from and to [`ARBOS_ADDRESS`]. Nitro
+    /// applies it without running the EVM.
+    Internal,
+}
+
+/// A retryable ticket as escrowed by a `SubmitRetryable` transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RetryableTicket {
+    pub id: B256,
+    pub from: Address,
+    pub to: Address,
+    pub call_value: U256,
+    pub data: Bytes,
+    pub beneficiary: Address,
+    pub timeout: u64,
+}
+
+impl RetryableTicket {
+    /// Account holding the ticket's call value until it is redeemed or cancelled.
+    pub(crate) fn escrow_address(&self) -> Address {
+        escrow_address(self.id)
+    }
+
+    /// Hash of the `ArbitrumRetryTx` that `ArbRetryableTx.redeem` schedules for this ticket:
+    /// its `sequence_num`th try, run with `donated_gas` refunded to `refund_to`.
+    ///
+    /// The retry is priced at the block's base fee and tagged with the chain id, both revm's
+    /// defaults here. A manual redeem never refunds a submission fee and caps the gas refund
+    /// at `U256::MAX`.
+    pub(crate) fn retry_tx_hash(
+        &self,
+        sequence_num: u64,
+        donated_gas: u64,
+        refund_to: Address,
+    ) -> B256 {
+        let chain_id = U256::from(CfgEnv::default().chain_id);
+        let gas_fee_cap = BlockEnv::default().basefee;
+
+        let mut payload = Vec::new();
+        chain_id.encode(&mut payload);
+        sequence_num.encode(&mut payload);
+        self.from.encode(&mut payload);
+        gas_fee_cap.encode(&mut payload);
+        donated_gas.encode(&mut payload);
+        self.to.encode(&mut payload);
+        self.call_value.encode(&mut payload);
+        self.data.encode(&mut payload);
+        self.id.encode(&mut payload);
+        refund_to.encode(&mut payload);
+        U256::MAX.encode(&mut payload);
+        U256::ZERO.encode(&mut payload);
+
+        let mut encoded = vec![ARBITRUM_RETRY_TX_TYPE];
+        Header { list: true, payload_length: payload.len() }.encode(&mut encoded);
+        encoded.extend(payload);
+        keccak256(encoded)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ArbitrumTxResult {
+    /// `None` for tx types that end before reaching the EVM.
+    pub result: Option<ExecutionResult>,
+    pub ticket: Option<RetryableTicket>,
+    /// Outcome of the auto-redeem scheduled by a `SubmitRetryable` transaction.
+    pub redeem: Option<ExecutionResult>,
+    /// Logs ArbOS emits outside of EVM execution, such as `TicketCreated`.
+    pub logs: Vec<Log>,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct ArbitrumTx {
+    pub tx_type: ArbitrumTxType,
+    /// L2 sender, already aliased for L1-originated tx types.
+    pub from: Address,
+    pub to: Address,
+    pub value: U256,
+    pub data: Bytes,
+    pub gas_limit: u64,
+    pub gas_fee_cap: U256,
+    pub nonce: u64,
+    pub request_id: B256,
+    pub deposit_value: U256,
+    pub max_submission_fee: U256,
+    pub beneficiary: Address,
+    pub fee_refund_address: Address,
+}
+
+impl ArbitrumTx {
+    fn new(tx_type: ArbitrumTxType, from: Address, to: Address) -> Self {
+        Self {
+            tx_type,
+            from,
+            to,
+            value: U256::ZERO,
+            data: Bytes::new(),
+            gas_limit: DEFAULT_GAS_LIMIT,
+            gas_fee_cap: U256::ZERO,
+            nonce: 0,
+            request_id: B256::ZERO,
+            deposit_value: U256::ZERO,
+            max_submission_fee: U256::ZERO,
+            beneficiary: from,
+            fee_refund_address: from,
+        }
+    }
+
+    pub(crate) fn deposit(l1_sender: Address, to: Address, value: U256) -> Self {
+        Self::new(ArbitrumTxType::Deposit, apply_l1_to_l2_alias(l1_sender), to).value(value)
+    }
+
+    pub(crate) fn unsigned(l1_sender: Address, to: Address, data: impl Into<Bytes>) -> Self {
+        Self::new(ArbitrumTxType::Unsigned, apply_l1_to_l2_alias(l1_sender), to).data(data)
+    }
+
+    pub(crate) fn contract(l1_sender: Address, to: Address, data: impl Into<Bytes>) -> Self {
+        Self::new(ArbitrumTxType::Contract, apply_l1_to_l2_alias(l1_sender), to).data(data)
+    }
+
+    /// A retryable submission. Without `gas_limit`/`gas_fee_cap` no auto-redeem is scheduled.
+    pub(crate) fn submit_retryable(l1_sender: Address, to: Address, data: impl Into<Bytes>) -> Self {
+        Self::new(ArbitrumTxType::SubmitRetryable, apply_l1_to_l2_alias(l1_sender), to)
+            .data(data)
+            .gas_limit(0)
+    }
+
+    /// An internal tx carrying an `ArbosActs` call.
+    pub(crate) fn internal(data: impl Into<Bytes>) -> Self {
+        Self::new(ArbitrumTxType::Internal, ARBOS_ADDRESS, ARBOS_ADDRESS).data(data)
+    }
+
+    /// The internal tx opening an L2 block `time_passed` seconds after the previous one.
+    pub(crate) fn start_block(l2_block_number: u64, time_passed: u64) -> Self {
+        let calldata = ArbosActs::startBlockCall {
+            l1BaseFee: U256::ZERO,
+            l1BlockNumber: 0,
+            l2BlockNumber: l2_block_number,
+            timePassed: time_passed,
+        };
+        Self::internal(calldata.abi_encode())
+    }
+
+    pub(crate) fn value(mut self, value: U256) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub(crate) fn data(mut self, data: impl Into<Bytes>) -> Self {
+        self.data = data.into();
+        self
+    }
+
+    pub(crate) fn gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    pub(crate) fn gas_fee_cap(mut self, gas_fee_cap: U256) -> Self {
+        self.gas_fee_cap = gas_fee_cap;
+        self
+    }
+
+    pub(crate) fn nonce(mut self, nonce: u64) -> Self {
+        self.nonce = nonce;
+        self
+    }
+
+    pub(crate) fn request_id(mut self, request_id: B256) -> Self {
+        self.request_id = request_id;
+        self
+    }
+
+    pub(crate) fn deposit_value(mut self, deposit_value: U256) -> Self {
+        self.deposit_value = deposit_value;
+        self
+    }
+
+    pub(crate) fn max_submission_fee(mut self, max_submission_fee: U256) -> Self {
+        self.max_submission_fee = max_submission_fee;
+        self
+    }
+
+    pub(crate) fn beneficiary(mut self, beneficiary: Address) -> Self {
+        self.beneficiary = beneficiary;
+        self
+    }
+
+    pub(crate) fn fee_refund_address(mut self, fee_refund_address: Address) -> Self {
+        self.fee_refund_address = fee_refund_address;
+        self
+    }
+
+    /// Ticket id of a retryable submission.
+    ///
+    /// Nitro uses the submission tx hash; the harness hashes the fields that make a ticket
+    /// unique instead, which is enough for tests that never see the L1 message.
+    pub(crate) fn ticket_id(&self) -> B256 {
+        keccak256(
+            [
+                self.request_id.as_slice(),
+                self.from.as_slice(),
+                self.to.as_slice(),
+                &self.value.to_be_bytes::<32>(),
+                self.data.as_ref(),
+            ]
+            .concat(),
+        )
+    }
+
+    pub(crate) fn execute<T: DatabaseRef>(
+        &self,
+        db: &mut CacheDB<T>,
+        basefee: U256,
+    ) -> ArbitrumTxResult
+    where
+        T::Error: Debug,
+    {
+        self.try_execute(db, basefee).unwrap()
+    }
+
+    /// Executes like [`ArbitrumTx::execute`], but returns the error if arbos-revm rejects
+    /// the transaction instead of panicking.
+    pub(crate) fn try_execute<T: DatabaseRef>(
+        &self,
+        db: &mut CacheDB<T>,
+        basefee: U256,
+    ) -> Result<ArbitrumTxResult, EVMError<T::Error>>
+    where
+        T::Error: Debug,
+    {
+        let receipt = match self.tx_type {
+            ArbitrumTxType::Deposit => {
+                mint(db, self.from, self.value);
+                transfer(db, self.from, self.to, self.value);
+
+                ArbitrumTxResult { result: None, ticket: None, redeem: None, logs: vec![] }
+            }
+            ArbitrumTxType::Unsigned | ArbitrumTxType::Contract => {
+                let tx = TxEnv {
+                    caller: self.from,
+                    transact_to: TxKind::Call(self.to),
+                    value: self.value,
+                    data: self.data.clone(),
+                    gas_limit: self.gas_limit,
+                    // Arbitrum ignores tips; L1-originated txs always pay the base fee.
+                    gas_price: basefee,
+                    nonce: (self.tx_type == ArbitrumTxType::Unsigned).then_some(self.nonce),
+                    ..Default::default()
+                };
+
+                ArbitrumTxResult {
+                    result: Some(try_transact(db, basefee, tx)?),
+                    ticket: None,
+                    redeem: None,
+                    logs: vec![],
+                }
+            }
+            ArbitrumTxType::SubmitRetryable => self.submit(db, basefee),
+            ArbitrumTxType::Internal => {
+                self.apply_internal(db);
+
+                ArbitrumTxResult { result: None, ticket: None, redeem: None, logs: vec![] }
+            }
+        };
+        Ok(receipt)
+    }
+
+    /// Follows Nitro's `ApplyInternalTxUpdate` for `startBlock`, which tries to reap two
+    /// retryables. L1 pricing and block hashes are left alone; nothing in the harness reads
+    /// them. Every harness block is at revm's default timestamp, so the new block's time is
+    /// that plus `timePassed`.
+    fn apply_internal<T: DatabaseRef>(&self, db: &mut CacheDB<T>)
+    where
+        T::Error: Debug,
+    {
+        let start_block = ArbosActs::startBlockCall::abi_decode(&self.data, true)
+            .expect("the harness only applies startBlock internal txs");
+        let now = DEFAULT_TIMESTAMP + start_block.timePassed;
+
+        for _ in 0..2 {
+            try_to_reap_one_retryable(db, now);
+        }
+    }
+
+    /// Follows Nitro's `SubmitRetryable` hook: the deposit is minted to the sender, the
+    /// submission fee is charged, call value moves to escrow and any prepaid gas runs the
+    /// auto-redeem. The full submission fee is charged and what is left of the deposit is
+    /// refunded to `fee_refund_address` up front.
+    fn submit<T: DatabaseRef>(&self, db: &mut CacheDB<T>, basefee: U256) -> ArbitrumTxResult
+    where
+        T::Error: Debug,
+    {
+        let ticket = RetryableTicket {
+            id: self.ticket_id(),
+            from: self.from,
+            to: self.to,
+            call_value: self.value,
+            data: self.data.clone(),
+            beneficiary: self.beneficiary,
+            timeout: DEFAULT_TIMESTAMP + RETRYABLE_LIFETIME_SECONDS,
+        };
+
+        let gas_price = self.gas_fee_cap.max(basefee);
+        let prepaid = gas_price * U256::from(self.gas_limit);
+        let auto_redeem = self.gas_limit > 0 && !self.gas_fee_cap.is_zero();
+        let excess = self
+            .deposit_value
+            .checked_sub(self.max_submission_fee + self.value)
+            .and_then(|excess| if auto_redeem { excess.checked_sub(prepaid) } else { Some(excess) })
+            .expect("deposit does not cover the retryable's fees and call value");
+
+        mint(db, self.from, self.deposit_value);
+        burn(db, self.from, self.max_submission_fee);
+        transfer(db, self.from, ticket.escrow_address(), self.value);
+        transfer(db, self.from, self.fee_refund_address, excess);
+
+        write_ticket(db, &ticket);
+        let logs = vec![Log {
+            address: ARB_RETRYABLE_TX,
+            data: ArbRetryableTx::TicketCreated { ticketId: ticket.id }.encode_log_data(),
+        }];
+
+        let redeem = auto_redeem.then(|| {
+            burn(db, self.from, prepaid);
+            // The auto-redeem is the ticket's first try, scheduled as sequence number 0.
+            let storage = ArbosStorage::retryables().open(ticket.id.as_slice());
+            storage.set(db, NUM_TRIES_OFFSET, U256::from(1));
+
+            let result = execute_retry(db, &ticket, self.gas_limit);
+
+            let unused = U256::from(self.gas_limit - result.gas_used()) * gas_price;
+            mint(db, self.fee_refund_address, unused);
+            result
+        });
+
+        ArbitrumTxResult { result: None, ticket: Some(ticket), redeem, logs }
+    }
+}
+
+/// Runs the `ArbitrumRetryTx` redeeming `ticket`, as scheduled by an auto-redeem or a
+/// `RedeemScheduled` event. Gas is prepaid or donated, so the retry pays no fees itself.
+///
+/// Scheduling the retry is what counts towards the ticket's `numTries`, so this leaves it
+/// alone. A successful redeem deletes the ticket; a failed one leaves it redeemable with its
+/// call value back in escrow.
+pub(crate) fn execute_retry<T: DatabaseRef>(
+    db: &mut CacheDB<T>,
+    ticket: &RetryableTicket,
+    gas_limit: u64,
+) -> ExecutionResult
+where
+    T::Error: Debug,
+{
+    transfer(db, ticket.escrow_address(), ticket.from, ticket.call_value);
+
+    let tx = TxEnv {
+        caller: ticket.from,
+        transact_to: TxKind::Call(ticket.to),
+        value: ticket.call_value,
+        data: ticket.data.clone(),
+        gas_limit,
+        gas_price: U256::ZERO,
+        nonce: None,
+        ..Default::default()
+    };
+    let result = transact(db, U256::ZERO, tx);
+
+    if result.is_success() {
+        delete_retryable(db, ticket.id);
+    } else {
+        transfer(db, ticket.from, ticket.escrow_address(), ticket.call_value);
+    }
+    result
+}
+
+/// A node of ArbOS's storage tree, addressed the way Nitro's `arbos/storage` package does.
+struct ArbosStorage {
+    key: Vec<u8>,
+}
+
+impl ArbosStorage {
+    fn root() -> Self {
+        Self { key: vec![] }
+    }
+
+    fn retryables() -> Self {
+        Self::root().open(&[2])
+    }
+
+    /// Per-program data in the `programs` subspace, keyed by code hash.
+    fn programs() -> Self {
+        Self::root().open(&[8]).open(&[1])
+    }
+
+    fn open(&self, id: &[u8]) -> Self {
+        Self {
+            key: keccak256([self.key.as_slice(), id].concat()).to_vec(),
+        }
+    }
+
+    fn slot(&self, key: B256) -> U256 {
+        let hashed = keccak256([self.key.as_slice(), &key[..31]].concat());
+
+        let mut mapped = B256::ZERO;
+        mapped[..31].copy_from_slice(&hashed[..31]);
+        mapped[31] = key[31];
+        mapped.into()
+    }
+
+    fn get_by_hash<T: DatabaseRef>(&self, db: &mut CacheDB<T>, key: B256) -> U256
+    where
+        T::Error: Debug,
+    {
+        db.storage(ARBOS_STATE_ADDRESS, self.slot(key)).unwrap()
+    }
+
+    fn set_by_hash<T: DatabaseRef>(&self, db: &mut CacheDB<T>, key: B256, value: U256)
+    where
+        T::Error: Debug,
+    {
+        let account = db.load_account(ARBOS_STATE_ADDRESS).unwrap();
+        if account.account_state == AccountState::NotExisting {
+            account.account_state = AccountState::None;
+        }
+        // ArbOS keeps its state account non-empty so it is never pruned.
+        account.info.nonce = account.info.nonce.max(1);
+        account.storage.insert(self.slot(key), value);
+    }
+
+    fn get<T: DatabaseRef>(&self, db: &mut CacheDB<T>, offset: u64) -> U256
+    where
+        T::Error: Debug,
+    {
+        self.get_by_hash(db, U256::from(offset).into())
+    }
+
+    fn set<T: DatabaseRef>(&self, db: &mut CacheDB<T>, offset: u64, value: U256)
+    where
+        T::Error: Debug,
+    {
+        self.set_by_hash(db, U256::from(offset).into(), value)
+    }
+
+    fn set_bytes<T: DatabaseRef>(&self, db: &mut CacheDB<T>, bytes: &[u8])
+    where
+        T::Error: Debug,
+    {
+        self.set(db, 0, U256::from(bytes.len()));
+        for (offset, chunk) in bytes.chunks(32).enumerate() {
+            self.set(db, offset as u64 + 1, U256::from_be_slice(chunk));
+        }
+    }
+}
+
+/// Offset of the ticket's beneficiary among its fields.
+const BENEFICIARY_OFFSET: u64 = 4;
+
+/// Offset of the ticket's timeout among its fields.
+const TIMEOUT_OFFSET: u64 = 5;
+
+/// Offset of the lifetimes `keepalive` has added beyond the ticket's timeout.
+const TIMEOUT_WINDOWS_LEFT_OFFSET: u64 = 6;
+
+/// Offset of the retry counter among a ticket's fields. Each scheduled retry takes the
+/// current count as its sequence number and bumps it: `ArbRetryableTx.redeem` does so in
+/// arbos-revm, the auto-redeem in [`ArbitrumTx::execute`].
+const NUM_TRIES_OFFSET: u64 = 0;
+
+fn ticket_fields(ticket: &RetryableTicket) -> [U256; 7] {
+    [
+        U256::ZERO,
+        U256::from_be_slice(ticket.from.as_slice()),
+        U256::from_be_slice(ticket.to.as_slice()),
+        ticket.call_value,
+        U256::from_be_slice(ticket.beneficiary.as_slice()),
+        U256::from(ticket.timeout),
+        U256::ZERO,
+    ]
+}
+
+/// Stores `ticket` the way `RetryableState.CreateRetryable` does and queues it for timeout.
+fn write_ticket<T: DatabaseRef>(db: &mut CacheDB<T>, ticket: &RetryableTicket)
+where
+    T::Error: Debug,
+{
+    let retryables = ArbosStorage::retryables();
+    let storage = retryables.open(ticket.id.as_slice());
+
+    for (offset, value) in ticket_fields(ticket).into_iter().enumerate() {
+        storage.set(db, offset as u64, value);
+    }
+    storage.open(&[1]).set_bytes(db, &ticket.data);
+
+    // Queue slots 0 and 1 hold the put and get offsets, both starting at 2.
+    let queue = retryables.open(&[0]);
+    let mut next_put = queue.get(db, 0).to::<u64>();
+    if next_put == 0 {
+        next_put = 2;
+        queue.set(db, 1, U256::from(2));
+    }
+    queue.set(db, next_put, ticket.id.into());
+    queue.set(db, 0, U256::from(next_put + 1));
+}
+
+/// Deletes the ticket `id` the way `RetryableState.DeleteRetryable` does, paying whatever is
+/// left in its escrow to the beneficiary.
+fn delete_retryable<T: DatabaseRef>(db: &mut CacheDB<T>, id: B256)
+where
+    T::Error: Debug,
+{
+    let storage = ArbosStorage::retryables().open(id.as_slice());
+    let beneficiary = Address::from_word(storage.get(db, BENEFICIARY_OFFSET).into());
+    let escrow = escrow_address(id);
+    let escrowed = db.load_account(escrow).unwrap().info.balance;
+    transfer(db, escrow, beneficiary, escrowed);
+
+    for offset in 0..=TIMEOUT_WINDOWS_LEFT_OFFSET {
+        storage.set(db, offset, U256::ZERO);
+    }
+    let calldata = storage.open(&[1]);
+    let len = calldata.get(db, 0).to::<usize>();
+    for offset in 0..=len.div_ceil(32) {
+        calldata.set(db, offset as u64, U256::ZERO);
+    }
+}
+
+/// Follows `RetryableState.TryToReapOneRetryable`: looks at the head of the timeout queue
+/// and, once its timeout has passed, either spends one of its extra lifetimes or deletes it.
+fn try_to_reap_one_retryable<T: DatabaseRef>(db: &mut CacheDB<T>, now: u64)
+where
+    T::Error: Debug,
+{
+    let retryables = ArbosStorage::retryables();
+    let queue = retryables.open(&[0]);
+    let (next_put, next_get) = (queue.get(db, 0).to::<u64>(), queue.get(db, 1).to::<u64>());
+    if next_put == next_get {
+        return;
+    }
+    let id = B256::from(queue.get(db, next_get));
+    let storage = retryables.open(id.as_slice());
+
+    let timeout = storage.get(db, TIMEOUT_OFFSET).to::<u64>();
+    if timeout != 0 && timeout >= now {
+        return;
+    }
+    queue.set(db, next_get, U256::ZERO);
+    queue.set(db, 1, U256::from(next_get + 1));
+    // A zero timeout means the ticket was already deleted and only its queue entry was left.
+    if timeout == 0 {
+        return;
+    }
+
+    let windows_left = storage.get(db, TIMEOUT_WINDOWS_LEFT_OFFSET).to::<u64>();
+    if windows_left == 0 {
+        delete_retryable(db, id);
+    } else {
+        storage.set(db, TIMEOUT_OFFSET, U256::from(timeout + RETRYABLE_LIFETIME_SECONDS));
+        storage.set(db, TIMEOUT_WINDOWS_LEFT_OFFSET, U256::from(windows_left - 1));
+    }
+}
+
+fn escrow_address(id: B256) -> Address {
+    Address::from_word(keccak256([b"retryable escrow".as_slice(), id.as_slice()].concat()))
+}
+
+/// What ArbOS records about an activated program, as `Programs.setProgram` packs it. Costs,
+/// the activation time and the cache flag are left out; the harness never reads them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct ProgramInfo {
+    pub version: u16,
+    /// Pages of memory the program starts with.
+    pub footprint: u16,
+}
+
+/// Records the program with `code_hash` as activated.
+pub(crate) fn write_program<T: DatabaseRef>(
+    db: &mut CacheDB<T>,
+    code_hash: B256,
+    info: ProgramInfo,
+) where
+    T::Error: Debug,
+{
+    let mut data = B256::ZERO;
+    data[0..2].copy_from_slice(&info.version.to_be_bytes());
+    data[6..8].copy_from_slice(&info.footprint.to_be_bytes());
+    ArbosStorage::programs().set_by_hash(db, code_hash, data.into());
+}
+
+/// The activation ArbOS has recorded for `code_hash`, if any.
+pub(crate) fn read_program<T: DatabaseRef>(
+    db: &mut CacheDB<T>,
+    code_hash: B256,
+) -> Option<ProgramInfo>
+where
+    T::Error: Debug,
+{
+    let data = B256::from(ArbosStorage::programs().get_by_hash(db, code_hash));
+    let version = u16::from_be_bytes([data[0], data[1]]);
+
+    (version != 0).then(|| ProgramInfo {
+        version,
+        footprint: u16::from_be_bytes([data[6], data[7]]),
+    })
+}
+
+fn transact<T: DatabaseRef>(db: &mut CacheDB<T>, basefee: U256, tx: TxEnv) -> ExecutionResult
+where
+    T::Error: Debug,
+{
+    try_transact(db, basefee, tx).unwrap()
+}
+
+fn try_transact<T: DatabaseRef>(
+    db: &mut CacheDB<T>,
+    basefee: U256,
+    tx: TxEnv,
+) -> Result<ExecutionResult, EVMError<T::Error>>
+where
+    T::Error: Debug,
+{
+    let evm = revm::Evm::builder()
+        .with_db(db)
+        .modify_block_env(|block| {
+            block.basefee = basefee;
+        })
+        .modify_tx_env(|tx_env: &mut TxEnv| {
+            *tx_env = tx;
+        });
+
+    evm.build().transact_commit()
+}
+
+fn mint<T: DatabaseRef>(db: &mut CacheDB<T>, address: Address, amount: U256)
+where
+    T::Error: Debug,
+{
+    let account = db.load_account(address).unwrap();
+    if account.account_state == AccountState::NotExisting {
+        account.account_state = AccountState::None;
+    }
+    account.info.balance += amount;
+}
+
+fn burn<T: DatabaseRef>(db: &mut CacheDB<T>, address: Address, amount: U256)
+where
+    T::Error: Debug,
+{
+    let account = db.load_account(address).unwrap();
+    account.info.balance = account
+        .info
+        .balance
+        .checked_sub(amount)
+        .expect("insufficient balance");
+}
+
+fn transfer<T: DatabaseRef>(db: &mut CacheDB<T>, from: Address, to: Address, amount: U256)
+where
+    T::Error: Debug,
+{
+    burn(db, from, amount);
+    mint(db, to, amount);
+}
@@ -0,0 +1,186 @@
+//! A base state with every fixture already deployed.
+//!
+//! arbos-revm activates a program the first time it is called, so every Stylus fixture is
+//! called once before the base state is frozen. A test's first call to a fixture then finds
+//! it activated and pays no activation cost, and no activation shows up in its state diff.
+//! The base state is built once per test binary, on first use, and each test works on its
+//! own clone, so tests still can't see each other's writes.
+
+use std::sync::OnceLock;
+
+use alloy_sol_types::{sol, SolCall};
+use revm::{
+    db::{CacheDB, EmptyDB},
+    primitives::{address, hex, Address, B256, TxEnv, TxKind, U256},
+};
+
+use super::multicall::Multicaller;
+use super::{deploy_solidity, deploy_wasm, setup_simple_test, DEPLOYER};
+
+const MULTICALL_BYTECODE: &[u8] = include_bytes!("../assets/multicall.wasm");
+const TEST_PROGRAM_BYTECODE: &[u8] = include_bytes!("../assets/test_program.wasm");
+const EMIT_LOG_BYTECODE: &[u8] = include_bytes!("../assets/emit_log.wasm");
+const HOSTIO_PROBE_BYTECODE: &[u8] = include_bytes!("../assets/hostio_probe.wasm");
+const CREATE_PROGRAM_BYTECODE: &[u8] = include_bytes!("../assets/create_program.wasm");
+const RECURSIVE_BYTECODE: &[u8] = include_bytes!("../assets/recursive.wasm");
+const BENCH_PROGRAM_BYTECODE: &[u8] = include_bytes!("../assets/bench_program.wasm");
+
+const MULTICALL_EVM_BYTECODE: &str = include_str!("../assets/Multicaller.bin");
+const HOSTIO_PROBE_EVM_BYTECODE: &str = include_str!("../assets/HostioProbe.bin");
+const CREATE_TEST_EVM_BYTECODE: &str = include_str!("../assets/CreateTest.bin");
+const RECURSIVE_EVM_BYTECODE: &str = include_str!("../assets/Recursive.bin");
+const BENCH_PROGRAM_EVM_BYTECODE: &str = include_str!("../assets/BenchProgram.bin");
+const EMIT_LOG_EVM_BYTECODE: &str = include_str!("../assets/EmitLog.bin");
+
+/// Where each fixture lives in the base state.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Fixtures {
+    pub multicall: Address,
+    pub test_program: Address,
+    pub emit_log: Address,
+    pub hostio_probe: Address,
+    pub create_program: Address,
+    pub recursive: Address,
+    pub bench_program: Address,
+
+    pub multicall_evm: Address,
+    pub hostio_probe_evm: Address,
+    pub create_test_evm: Address,
+    pub recursive_evm: Address,
+    pub bench_program_evm: Address,
+    pub emit_log_evm: Address,
+}
+
+impl Fixtures {
+    /// Every fixture with its name, Stylus programs first, in the order they are deployed.
+    pub(crate) fn all(&self) -> [(&'static str, Address); 13] {
+        [
+            ("multicall", self.multicall),
+            ("test_program", self.test_program),
+            ("emit_log", self.emit_log),
+            ("hostio_probe", self.hostio_probe),
+            ("create_program", self.create_program),
+            ("recursive", self.recursive),
+            ("bench_program", self.bench_program),
+            ("multicall_evm", self.multicall_evm),
+            ("hostio_probe_evm", self.hostio_probe_evm),
+            ("create_test_evm", self.create_test_evm),
+            ("recursive_evm", self.recursive_evm),
+            ("bench_program_evm", self.bench_program_evm),
+            ("emit_log_evm", self.emit_log_evm),
+        ]
+    }
+}
+
+/// Sends the warm-up calls, so [`DEPLOYER`]'s nonce only counts deployments.
+const WARM_UP_CALLER: Address = address!("3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a");
+
+// A call to each Stylus fixture that succeeds without changing its state.
+sol! {
+    interface IWarmUp {
+        function getStorage(bytes32 key) external returns (bytes32);
+        function emitLog(bytes32[] topics, bytes data) external;
+        function contractAddress() external returns (address);
+        function tryCreate(bytes init_code, uint256 endowment) external returns (address, bytes);
+        function recurseInternal(uint64 depth) external returns (uint64);
+        function keccakLoop(bytes32 seed, uint64 count) external returns (bytes32);
+    }
+}
+
+/// Calls `program` once so arbos-revm activates it.
+fn warm_up(db: &mut CacheDB<EmptyDB>, name: &str, program: Address, data: Vec<u8>) {
+    let evm = revm::Evm::builder()
+        .with_db(db)
+        .modify_tx_env(|tx: &mut TxEnv| {
+            tx.caller = WARM_UP_CALLER;
+            tx.transact_to = TxKind::Call(program);
+            tx.data = data.into();
+            tx.gas_limit = 1_000_000_000;
+        });
+
+    let result = evm.build().transact_commit().unwrap();
+    assert!(result.is_success(), "warming up {}: {:?}", name, result);
+}
+
+struct BaseState {
+    db: CacheDB<EmptyDB>,
+    fixtures: Fixtures,
+}
+
+static BASE_STATE: OnceLock<BaseState> = OnceLock::new();
+
+fn build() -> BaseState {
+    let mut db = CacheDB::new(EmptyDB::new());
+    setup_simple_test(&mut db);
+
+    let mut wasm = |bytecode: &[u8]| deploy_wasm(&mut db, bytecode.to_vec(), DEPLOYER);
+    let multicall = wasm(MULTICALL_BYTECODE);
+    let test_program = wasm(TEST_PROGRAM_BYTECODE);
+    let emit_log = wasm(EMIT_LOG_BYTECODE);
+    let hostio_probe = wasm(HOSTIO_PROBE_BYTECODE);
+    let create_program = wasm(CREATE_PROGRAM_BYTECODE);
+    let recursive = wasm(RECURSIVE_BYTECODE);
+    let bench_program = wasm(BENCH_PROGRAM_BYTECODE);
+
+    let warm_up_calls = [
+        ("multicall", multicall, Multicaller::multicallCall { calls: vec![] }.abi_encode()),
+        ("test_program", test_program, IWarmUp::getStorageCall { key: B256::ZERO }.abi_encode()),
+        (
+            "emit_log",
+            emit_log,
+            IWarmUp::emitLogCall { topics: vec![], data: Default::default() }.abi_encode(),
+        ),
+        ("hostio_probe", hostio_probe, IWarmUp::contractAddressCall {}.abi_encode()),
+        // The factory can't afford the endowment, so the create fails before spending its
+        // nonce.
+        (
+            "create_program",
+            create_program,
+            IWarmUp::tryCreateCall { init_code: Default::default(), endowment: U256::MAX }
+                .abi_encode(),
+        ),
+        ("recursive", recursive, IWarmUp::recurseInternalCall { depth: 0 }.abi_encode()),
+        (
+            "bench_program",
+            bench_program,
+            IWarmUp::keccakLoopCall { seed: B256::ZERO, count: 0 }.abi_encode(),
+        ),
+    ];
+    for (name, program, data) in warm_up_calls {
+        warm_up(&mut db, name, program, data);
+    }
+
+    let mut solidity = |bytecode: &str| deploy_solidity(&mut db, hex::decode(bytecode).unwrap(), DEPLOYER);
+    let multicall_evm = solidity(MULTICALL_EVM_BYTECODE);
+    let hostio_probe_evm = solidity(HOSTIO_PROBE_EVM_BYTECODE);
+    let create_test_evm = solidity(CREATE_TEST_EVM_BYTECODE);
+    let recursive_evm = solidity(RECURSIVE_EVM_BYTECODE);
+    let bench_program_evm = solidity(BENCH_PROGRAM_EVM_BYTECODE);
+    let emit_log_evm = solidity(EMIT_LOG_EVM_BYTECODE);
+
+    BaseState {
+        db,
+        fixtures: Fixtures {
+            multicall,
+            test_program,
+            emit_log,
+            hostio_probe,
+            create_program,
+            recursive,
+            bench_program,
+            multicall_evm,
+            hostio_probe_evm,
+            create_test_evm,
+            recursive_evm,
+            bench_program_evm,
+            emit_log_evm,
+        },
+    }
+}
+
+/// A private copy of the base state: [`DEPLOYER`] funded as by [`setup_simple_test`], every
+/// fixture deployed by it, and every Stylus fixture activated.
+pub(crate) fn base_state() -> (CacheDB<EmptyDB>, Fixtures) {
+    let base = BASE_STATE.get_or_init(build);
+    (base.db.clone(), base.fixtures)
+}
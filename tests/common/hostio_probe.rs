@@ -0,0 +1,28 @@
+//! Decoding for hostio_probe's `context()`. Its outputs are unnamed in the exported
+//! interface, so the tests read them through [`Context`] instead of `_0` to `_3`.
+
+use alloy_sol_types::{sol, SolCall};
+use revm::primitives::{Address, U256};
+
+sol!("tests/assets/abi/hostio_probe.sol");
+
+/// What `context()` reports about the call it runs in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Context {
+    pub sender: Address,
+    pub origin: Address,
+    pub value: U256,
+    pub gas_price: U256,
+}
+
+/// Decodes the output of a successful `context()` call.
+pub(crate) fn decode_context(output: &[u8]) -> Context {
+    let returns = IHostioProbe::contextCall::abi_decode_returns(output, true).unwrap();
+
+    Context {
+        sender: returns._0,
+        origin: returns._1,
+        value: returns._2,
+        gas_price: returns._3,
+    }
+}
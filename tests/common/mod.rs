@@ -1,106 +1,33 @@
-use revm::{
-    db::{CacheDB, EmptyDB},
-    primitives::{
-        address, bytes::Bytes, keccak256, AccountInfo, Address, Bytecode, SpecId::LATEST, TxEnv,
-        TxKind, B256, U256,
-    },
-    Database, DatabaseRef, STYLUS_MAGIC_BYTES,
-};
-
-pub(crate) const DEPLOYER: Address = address!("Bd770416a3345F91E4B34576cb804a576fa48EB1");
-
-pub(crate) fn setup_simple_test(db: &mut CacheDB<EmptyDB>) {
-    let mut info = AccountInfo::default();
-    info.balance = U256::from(1e18);
-    db.insert_account_info(DEPLOYER, info);
-}
+#![allow(dead_code, unused_imports)]
 
-pub(crate) fn deploy_wasm<T: DatabaseRef>(
-    db: &mut CacheDB<T>,
-    bytecode: Vec<u8>,
-    deployer: Address,
-) -> Address {
-    let nonce = db
-        .accounts
-        .get(&deployer)
-        .and_then(|acc| Some(acc.info.nonce))
-        .unwrap_or_default();
-    let deployed_address = deployer.create(nonce);
+use revm::primitives::B256;
 
-    let bytecode = wasm_contract_init_code(bytecode);
-
-    let evm = revm::Evm::builder()
-        .with_db(db)
-        .with_spec_id(LATEST)
-        .modify_tx_env(|tx: &mut TxEnv| {
-            tx.caller = deployer;
-            tx.transact_to = TxKind::Create;
-            tx.data = bytecode.into();
-        })
-        .modify_cfg_env(|cfg| {
-            cfg.limit_contract_code_size = Some(0x6000 * 4); 
-        });
-
-    match evm.build().transact_commit() {
-        Ok(res) => {
-            res
-        },
-        Err(_) => {
-            panic!("Failed to deploy contract");
-        }
-    };
-
-    deployed_address
-}
-
-pub(crate) fn deploy_solidity(
-    db: &mut CacheDB<EmptyDB>,
-    bytecode: Vec<u8>,
-    deployer: Address,
-) -> Address {
-    let nonce = db
-        .accounts
-        .get(&deployer)
-        .and_then(|acc| Some(acc.info.nonce))
-        .unwrap_or_default();
-    let deployed_address = deployer.create(nonce);
-
-    let evm = revm::Evm::builder()
-        .with_db(db)
-        .with_spec_id(LATEST)
-        .modify_tx_env(|tx: &mut TxEnv| {
-            tx.caller = deployer;
-            tx.transact_to = TxKind::Create;
-            tx.data = bytecode.into();
-        });
-
-    match evm.build().transact_commit() {
-        Ok(res) => {
-            res
-        },
-        Err(_) => {
-            panic!("Failed to deploy contract");
-        }
-    };
-
-    deployed_address
-}
-
-pub(crate) fn wasm_contract_init_code(bytecode: Vec<u8>) -> Vec<u8> {
-    let mut bytecode = [Bytes::from(STYLUS_MAGIC_BYTES), Bytes::from(bytecode)].concat();
+pub(crate) use arbos_revm_tests::{
+    compress_wasm, configure, deploy_solidity, deploy_wasm, evm_contract_init_code, gas,
+    multicall, next_create_address, replay, setup_simple_test, state_diff, state_dump,
+    try_deploy_wasm, wasm_contract_init_code, DEPLOYER, EVM_CHILD_RUNTIME, MAX_WASM_SIZE,
+    STYLUS_MAX_CODE_SIZE, STYLUS_MAX_INITCODE_SIZE,
+};
 
-    let mut deploy = vec![];
-    deploy.push(revm::interpreter::opcode::PUSH32);
-    deploy.append(&mut U256::from(bytecode.len()).to_be_bytes_vec());
-    deploy.push(revm::interpreter::opcode::DUP1);
-    deploy.push(revm::interpreter::opcode::PUSH1);
-    deploy.push(42);
-    deploy.push(revm::interpreter::opcode::PUSH1);
-    deploy.push(0);
-    deploy.push(revm::interpreter::opcode::CODECOPY);
-    deploy.push(revm::interpreter::opcode::PUSH1);
-    deploy.push(0);
-    deploy.push(revm::interpreter::opcode::RETURN);
-    deploy.append(&mut bytecode);
-    deploy
+pub(crate) mod activation;
+pub(crate) mod arbitrum;
+pub(crate) mod fixtures;
+pub(crate) mod hostio_probe;
+pub(crate) mod signing;
+
+/// Init code that reverts with `data`.
+pub(crate) fn reverting_init_code(data: B256) -> Vec<u8> {
+    let mut code = vec![revm::interpreter::opcode::PUSH32];
+    code.extend_from_slice(data.as_slice());
+    code.extend_from_slice(&[
+        revm::interpreter::opcode::PUSH1,
+        0,
+        revm::interpreter::opcode::MSTORE,
+        revm::interpreter::opcode::PUSH1,
+        32,
+        revm::interpreter::opcode::PUSH1,
+        0,
+        revm::interpreter::opcode::REVERT,
+    ]);
+    code
 }
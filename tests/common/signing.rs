@@ -0,0 +1,311 @@
+//! Signed transactions, for tests of the validation revm runs before execution.
+//!
+//! The other helpers set `TxEnv::caller` directly, so nothing checks who sent a
+//! transaction. Transactions built here are signed with deterministic test keys, encoded
+//! as EIP-2718 bytes and decoded back into a `TxEnv` with the caller recovered from the
+//! signature, as a node would.
+
+use std::fmt::Debug;
+
+use alloy_consensus::{
+    SignableTransaction, Signed, Transaction, TxEip1559, TxEip2930, TxEip7702, TxEnvelope,
+    TxLegacy,
+};
+use alloy_eips::{
+    eip2718::{Decodable2718, Encodable2718},
+    eip2930::AccessList,
+    eip7702::{Authorization, SignedAuthorization},
+};
+use alloy_primitives::PrimitiveSignature;
+use alloy_signer::SignerSync;
+use alloy_signer_local::PrivateKeySigner;
+use revm::{
+    db::CacheDB,
+    primitives::{
+        keccak256, Address, AuthorizationList, Bytes, EVMError, ExecutionResult, SpecId::LATEST,
+        TxEnv, TxKind, U256,
+    },
+    DatabaseRef,
+};
+
+use super::configure;
+
+/// Chain ID of a local Nitro dev chain, which every signed transaction targets by default.
+pub(crate) const TEST_CHAIN_ID: u64 = 412346;
+
+const DEFAULT_GAS_LIMIT: u64 = 1_000_000;
+const DEFAULT_MAX_FEE_PER_GAS: u128 = 1_000_000_000;
+
+/// The `index`th test key. Keys are derived from their index, so addresses are stable
+/// across runs.
+pub(crate) fn test_key(index: u64) -> PrivateKeySigner {
+    let secret = keccak256(format!("arbos-revm-tests key {}", index));
+    PrivateKeySigner::from_bytes(&secret).unwrap()
+}
+
+/// Has `signer` authorize delegating its account to `address` on [`TEST_CHAIN_ID`].
+pub(crate) fn sign_authorization(
+    signer: &PrivateKeySigner,
+    address: Address,
+    nonce: u64,
+) -> SignedAuthorization {
+    let authorization = Authorization {
+        chain_id: U256::from(TEST_CHAIN_ID),
+        address,
+        nonce,
+    };
+    let signature = signer.sign_hash_sync(&authorization.signature_hash()).unwrap();
+    authorization.into_signed(signature)
+}
+
+fn signed<T: SignableTransaction<PrimitiveSignature>>(tx: T, signer: &PrivateKeySigner) -> Signed<T> {
+    let signature = signer.sign_hash_sync(&tx.signature_hash()).unwrap();
+    tx.into_signed(signature)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SignedTxType {
+    Legacy,
+    Eip2930,
+    Eip1559,
+    Eip7702,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct SignedTx {
+    pub tx_type: SignedTxType,
+    /// `None` only for pre-EIP-155 legacy transactions.
+    pub chain_id: Option<u64>,
+    pub nonce: u64,
+    pub to: TxKind,
+    pub value: U256,
+    pub data: Bytes,
+    pub gas_limit: u64,
+    /// The gas price of legacy and EIP-2930 transactions.
+    pub max_fee_per_gas: u128,
+    /// Ignored by legacy and EIP-2930 transactions.
+    pub max_priority_fee_per_gas: u128,
+    pub access_list: AccessList,
+    pub authorizations: Vec<SignedAuthorization>,
+}
+
+impl SignedTx {
+    pub(crate) fn new(tx_type: SignedTxType, to: TxKind) -> Self {
+        Self {
+            tx_type,
+            chain_id: Some(TEST_CHAIN_ID),
+            nonce: 0,
+            to,
+            value: U256::ZERO,
+            data: Bytes::new(),
+            gas_limit: DEFAULT_GAS_LIMIT,
+            max_fee_per_gas: DEFAULT_MAX_FEE_PER_GAS,
+            max_priority_fee_per_gas: 0,
+            access_list: AccessList::default(),
+            authorizations: vec![],
+        }
+    }
+
+    pub(crate) fn legacy(to: TxKind) -> Self {
+        Self::new(SignedTxType::Legacy, to)
+    }
+
+    pub(crate) fn eip2930(to: TxKind) -> Self {
+        Self::new(SignedTxType::Eip2930, to)
+    }
+
+    pub(crate) fn eip1559(to: TxKind) -> Self {
+        Self::new(SignedTxType::Eip1559, to)
+    }
+
+    /// EIP-7702 transactions can't create contracts, so they always have a recipient.
+    pub(crate) fn eip7702(to: Address) -> Self {
+        Self::new(SignedTxType::Eip7702, TxKind::Call(to))
+    }
+
+    pub(crate) fn chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Signs without replay protection, as a pre-EIP-155 legacy transaction.
+    pub(crate) fn without_chain_id(mut self) -> Self {
+        self.chain_id = None;
+        self
+    }
+
+    pub(crate) fn nonce(mut self, nonce: u64) -> Self {
+        self.nonce = nonce;
+        self
+    }
+
+    pub(crate) fn value(mut self, value: U256) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub(crate) fn data(mut self, data: impl Into<Bytes>) -> Self {
+        self.data = data.into();
+        self
+    }
+
+    pub(crate) fn gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    pub(crate) fn max_fee_per_gas(mut self, max_fee_per_gas: u128) -> Self {
+        self.max_fee_per_gas = max_fee_per_gas;
+        self
+    }
+
+    pub(crate) fn max_priority_fee_per_gas(mut self, max_priority_fee_per_gas: u128) -> Self {
+        self.max_priority_fee_per_gas = max_priority_fee_per_gas;
+        self
+    }
+
+    pub(crate) fn access_list(mut self, access_list: AccessList) -> Self {
+        self.access_list = access_list;
+        self
+    }
+
+    pub(crate) fn authorization(mut self, authorization: SignedAuthorization) -> Self {
+        self.authorizations.push(authorization);
+        self
+    }
+
+    /// Most an account has to hold for this transaction to pass the balance check.
+    pub(crate) fn max_cost(&self) -> U256 {
+        U256::from(self.gas_limit) * U256::from(self.max_fee_per_gas) + self.value
+    }
+
+    fn typed_chain_id(&self) -> u64 {
+        self.chain_id
+            .unwrap_or_else(|| panic!("{:?} transactions need a chain ID", self.tx_type))
+    }
+
+    pub(crate) fn sign(&self, signer: &PrivateKeySigner) -> TxEnvelope {
+        match self.tx_type {
+            SignedTxType::Legacy => signed(
+                TxLegacy {
+                    chain_id: self.chain_id,
+                    nonce: self.nonce,
+                    gas_price: self.max_fee_per_gas,
+                    gas_limit: self.gas_limit,
+                    to: self.to,
+                    value: self.value,
+                    input: self.data.clone(),
+                },
+                signer,
+            )
+            .into(),
+            SignedTxType::Eip2930 => signed(
+                TxEip2930 {
+                    chain_id: self.typed_chain_id(),
+                    nonce: self.nonce,
+                    gas_price: self.max_fee_per_gas,
+                    gas_limit: self.gas_limit,
+                    to: self.to,
+                    value: self.value,
+                    access_list: self.access_list.clone(),
+                    input: self.data.clone(),
+                },
+                signer,
+            )
+            .into(),
+            SignedTxType::Eip1559 => signed(
+                TxEip1559 {
+                    chain_id: self.typed_chain_id(),
+                    nonce: self.nonce,
+                    gas_limit: self.gas_limit,
+                    max_fee_per_gas: self.max_fee_per_gas,
+                    max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+                    to: self.to,
+                    value: self.value,
+                    access_list: self.access_list.clone(),
+                    input: self.data.clone(),
+                },
+                signer,
+            )
+            .into(),
+            SignedTxType::Eip7702 => {
+                let TxKind::Call(to) = self.to else {
+                    panic!("EIP-7702 transactions can't create contracts");
+                };
+
+                signed(
+                    TxEip7702 {
+                        chain_id: self.typed_chain_id(),
+                        nonce: self.nonce,
+                        gas_limit: self.gas_limit,
+                        max_fee_per_gas: self.max_fee_per_gas,
+                        max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+                        to,
+                        value: self.value,
+                        access_list: self.access_list.clone(),
+                        authorization_list: self.authorizations.clone(),
+                        input: self.data.clone(),
+                    },
+                    signer,
+                )
+                .into()
+            }
+        }
+    }
+
+    /// The signed transaction as EIP-2718 bytes, as it would be sent to a node.
+    pub(crate) fn encode(&self, signer: &PrivateKeySigner) -> Bytes {
+        self.sign(signer).encoded_2718().into()
+    }
+}
+
+/// Decodes an EIP-2718 transaction into the `TxEnv` revm executes, recovering the caller
+/// from its signature.
+pub(crate) fn decode_tx_env(mut raw: &[u8]) -> TxEnv {
+    let envelope = TxEnvelope::decode_2718(&mut raw).expect("Malformed transaction");
+    let caller = envelope.recover_signer().expect("Invalid signature");
+
+    TxEnv {
+        caller,
+        gas_limit: envelope.gas_limit(),
+        // Legacy and EIP-2930 transactions report their gas price as the fee cap.
+        gas_price: U256::from(envelope.max_fee_per_gas()),
+        gas_priority_fee: envelope.max_priority_fee_per_gas().map(U256::from),
+        transact_to: envelope.kind(),
+        value: envelope.value(),
+        data: envelope.input().clone(),
+        nonce: Some(envelope.nonce()),
+        chain_id: envelope.chain_id(),
+        access_list: envelope
+            .access_list()
+            .map(|access_list| access_list.0.clone())
+            .unwrap_or_default(),
+        authorization_list: envelope
+            .authorization_list()
+            .map(|authorizations| AuthorizationList::Signed(authorizations.to_vec())),
+        ..Default::default()
+    }
+}
+
+/// Decodes and executes `raw` on a chain with [`TEST_CHAIN_ID`], leaving every validation
+/// check enabled.
+pub(crate) fn transact_raw<T: DatabaseRef>(
+    db: &mut CacheDB<T>,
+    raw: &[u8],
+) -> Result<ExecutionResult, EVMError<T::Error>>
+where
+    T::Error: Debug,
+{
+    let tx = decode_tx_env(raw);
+
+    let evm = revm::Evm::builder()
+        .with_db(db)
+        .with_spec_id(LATEST)
+        .modify_tx_env(|env: &mut TxEnv| *env = tx)
+        .modify_cfg_env(|cfg| {
+            configure(cfg);
+            cfg.chain_id = TEST_CHAIN_ID;
+        });
+
+    evm.build().transact_commit()
+}
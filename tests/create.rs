@@ -1,36 +1,33 @@
-use common::{deploy_wasm, setup_simple_test, wasm_contract_init_code, DEPLOYER};
+use arbutil::Color;
+use common::fixtures::base_state;
+use common::{
+    activation, next_create_address, reverting_init_code, wasm_contract_init_code, DEPLOYER,
+};
 use revm::arbos::STYLUS_MAGIC_BYTES;
 use revm::db::{CacheDB, EmptyDB};
 use revm::primitives::bytes::Bytes;
-use revm::primitives::{address, ExecutionResult, TxEnv, TxKind, B256, U256};
+use revm::primitives::{
+    keccak256, AccountInfo, Address, ExecutionResult, TxEnv, TxKind, B256, U256,
+};
 use alloy_sol_types::{sol, SolCall};
 
-const CREATE_PROGRAM_BYTECODE: &[u8] = include_bytes!("assets/create_program.wasm");
 const EMIT_LOG_PROGRAM_BYTECODE: &[u8] = include_bytes!("assets/emit_log.wasm");
 
 mod common;
 
-sol!{
-    contract CreateTest {
-        function create(bytes memory init_code, uint256 endowment) external returns (address);
-        function create2(bytes memory init_code, bytes32 salt, uint256 endowment) external returns (address);
-    }
-}
+sol!("tests/assets/abi/create_program.sol");
 
 fn create_test(balance: U256, endowment: U256) {
-    let mut db = CacheDB::new(EmptyDB::new());
-
-    setup_simple_test(&mut db);
-
-    let create_address = deploy_wasm(&mut db, CREATE_PROGRAM_BYTECODE.to_vec(), DEPLOYER);
+    let (mut db, fixtures) = base_state();
+    let create_address = fixtures.create_program;
 
     db.load_account(create_address).unwrap().info.balance = balance;
 
     let code = wasm_contract_init_code(EMIT_LOG_PROGRAM_BYTECODE.to_vec());
 
-    let expected_address = create_address.create(1);
+    let expected_address = next_create_address(&db, create_address);
 
-    let calldata = CreateTest::createCall {
+    let calldata = ICreateProgram::createCall {
         init_code: code.into(),
         endowment,
     };
@@ -76,17 +73,14 @@ pub fn create_with_endowment() {
 #[test]
 pub fn create_with_endowment_exceeds_balance() {
     let endowment = U256::from(1.5e18);
-    let mut db = CacheDB::new(EmptyDB::new());
-
-    setup_simple_test(&mut db);
-
-    let create_address = deploy_wasm(&mut db, CREATE_PROGRAM_BYTECODE.to_vec(), DEPLOYER);
+    let (mut db, fixtures) = base_state();
+    let create_address = fixtures.create_program;
 
     db.load_account(create_address).unwrap().info.balance = U256::from(0.5e18);
 
     let code = wasm_contract_init_code(EMIT_LOG_PROGRAM_BYTECODE.to_vec());
 
-    let calldata = CreateTest::createCall {
+    let calldata = ICreateProgram::createCall {
         init_code: code.into(),
         endowment,
     };
@@ -112,18 +106,18 @@ pub fn create_with_endowment_exceeds_balance() {
 
 #[test]
 pub fn create_2() {
-    let mut db = CacheDB::new(EmptyDB::new());
+    let (mut db, fixtures) = base_state();
 
-    let deployer = address!("Bd770416a3345F91E4B34576cb804a576fa48EB1");
+    let deployer = DEPLOYER;
 
-    let deployed_address = common::deploy_wasm(&mut db, CREATE_PROGRAM_BYTECODE.to_vec(), deployer);
+    let deployed_address = fixtures.create_program;
 
     let endowment = U256::from(0);
     let salt = B256::from(U256::from(1234));
 
     let code = wasm_contract_init_code(EMIT_LOG_PROGRAM_BYTECODE.to_vec());
 
-    let calldata = CreateTest::create2Call {
+    let calldata = ICreateProgram::create2Call {
         init_code: code.clone().into(),
         salt,
         endowment,
@@ -160,3 +154,205 @@ pub fn create_2() {
         .concat()
     );
 }
+
+/// Init code that loops until it runs out of gas.
+const OUT_OF_GAS_INIT_CODE: &[u8] = &[
+    revm::interpreter::opcode::JUMPDEST,
+    revm::interpreter::opcode::PUSH1,
+    0,
+    revm::interpreter::opcode::JUMP,
+];
+
+const FACTORY_BALANCE: u64 = 1_000_000_000_000_000_000;
+
+struct TryCreate {
+    db: CacheDB<EmptyDB>,
+    factory: Address,
+    factory_nonce: u64,
+    /// Where the child lands if creation succeeds.
+    child: Address,
+    returned: Address,
+    revert_data: Bytes,
+}
+
+fn try_create(init_code: Vec<u8>, endowment: U256) -> TryCreate {
+    try_create_with_gas(init_code, endowment, 1e9 as u64)
+}
+
+/// Like [`try_create`], with the transaction limited to `gas_limit`.
+fn try_create_with_gas(init_code: Vec<u8>, endowment: U256, gas_limit: u64) -> TryCreate {
+    let (mut db, fixtures) = base_state();
+    let factory = fixtures.create_program;
+    db.load_account(factory).unwrap().info.balance = U256::from(FACTORY_BALANCE);
+    let factory_nonce = db.load_account(factory).unwrap().info.nonce;
+    let child = next_create_address(&db, factory);
+
+    let calldata = ICreateProgram::tryCreateCall {
+        init_code: init_code.into(),
+        endowment,
+    };
+
+    let result = {
+        let evm = revm::Evm::builder()
+            .with_db(&mut db)
+            .modify_tx_env(|tx: &mut TxEnv| {
+                tx.caller = DEPLOYER;
+                tx.transact_to = TxKind::Call(factory);
+                tx.data = calldata.abi_encode().into();
+                tx.gas_limit = gas_limit;
+            });
+        evm.build().transact_commit().unwrap()
+    };
+    assert!(result.is_success(), "{:?}", result);
+
+    let returned = ICreateProgram::tryCreateCall::abi_decode_returns(result.output().unwrap(), true).unwrap();
+
+    TryCreate {
+        db,
+        factory,
+        factory_nonce,
+        child,
+        returned: returned._0,
+        revert_data: returned._1.into(),
+    }
+}
+
+impl TryCreate {
+    fn factory_info(&mut self) -> AccountInfo {
+        self.db.load_account(self.factory).unwrap().info.clone()
+    }
+
+    fn child_info(&mut self) -> AccountInfo {
+        self.db.load_account(self.child).unwrap().info.clone()
+    }
+}
+
+#[test]
+pub fn create_with_reverting_init_code() {
+    let reason = keccak256("init code reverted");
+    let endowment = U256::from(0.5e18);
+
+    let mut outcome = try_create(reverting_init_code(reason), endowment);
+
+    assert_eq!(outcome.returned, Address::ZERO);
+    assert_eq!(outcome.revert_data, Bytes::from(reason.to_vec()));
+
+    // The nonce is spent before the init code runs; the endowment comes back.
+    let factory = outcome.factory_info();
+    assert_eq!(factory.nonce, outcome.factory_nonce + 1);
+    assert_eq!(factory.balance, U256::from(FACTORY_BALANCE));
+    assert!(outcome.child_info().is_empty());
+}
+
+#[test]
+pub fn create_bubbles_init_code_revert_reason() {
+    let reason = keccak256("init code reverted");
+    let (mut db, fixtures) = base_state();
+    let factory = fixtures.create_program;
+    let factory_nonce = db.load_account(factory).unwrap().info.nonce;
+
+    let calldata = ICreateProgram::createCall {
+        init_code: reverting_init_code(reason).into(),
+        endowment: U256::ZERO,
+    };
+
+    {
+        let evm = revm::Evm::builder()
+            .with_db(&mut db)
+            .modify_tx_env(|tx: &mut TxEnv| {
+                tx.caller = DEPLOYER;
+                tx.transact_to = TxKind::Call(factory);
+                tx.data = calldata.abi_encode().into();
+                tx.gas_limit = 1e9 as u64;
+            });
+        let result = evm.build().transact_commit().unwrap();
+
+        match result {
+            ExecutionResult::Revert { output, .. } => {
+                assert_eq!(output, Bytes::from(reason.to_vec()));
+            }
+            _ => panic!("Expected revert: {:?}", result),
+        }
+    }
+
+    // The whole call reverted, nonce increment included.
+    assert_eq!(db.load_account(factory).unwrap().info.nonce, factory_nonce);
+}
+
+#[test]
+pub fn create_with_empty_code() {
+    let endowment = U256::from(0.5e18);
+
+    let mut outcome = try_create(
+        vec![
+            revm::interpreter::opcode::PUSH1,
+            0,
+            revm::interpreter::opcode::PUSH1,
+            0,
+            revm::interpreter::opcode::RETURN,
+        ],
+        endowment,
+    );
+
+    assert_eq!(outcome.returned, outcome.child);
+    assert!(outcome.revert_data.is_empty());
+
+    let factory = outcome.factory_info();
+    assert_eq!(factory.nonce, outcome.factory_nonce + 1);
+    assert_eq!(factory.balance, U256::from(FACTORY_BALANCE) - endowment);
+
+    let child = outcome.child_info();
+    assert_eq!(child.nonce, 1);
+    assert_eq!(child.balance, endowment);
+    assert!(child.code.unwrap_or_default().is_empty());
+}
+
+#[test]
+pub fn create_with_invalid_wasm() {
+    let invalid = Bytes::from(
+        wat::parse_str(include_str!("fixtures/invalid-wasm/missing-entrypoint.wat")).unwrap(),
+    );
+
+    let mut outcome = try_create(wasm_contract_init_code(invalid.to_vec()), U256::ZERO);
+
+    // Deployment only stores the code; nothing validates it until the program is called.
+    assert_eq!(outcome.returned, outcome.child);
+    assert!(outcome.revert_data.is_empty());
+    assert_eq!(outcome.factory_info().nonce, outcome.factory_nonce + 1);
+    assert_eq!(
+        outcome.child_info().code.unwrap().original_bytes().to_vec(),
+        [Bytes::from(STYLUS_MAGIC_BYTES), invalid].concat()
+    );
+
+    let child = outcome.child;
+    let evm = revm::Evm::builder()
+        .with_db(&mut outcome.db)
+        .modify_tx_env(|tx: &mut TxEnv| {
+            tx.caller = DEPLOYER;
+            tx.transact_to = TxKind::Call(child);
+            tx.gas_limit = 1e9 as u64;
+        });
+    let result = evm.build().transact_commit().unwrap();
+
+    let error = activation::parse_error(format!(
+        "missing export with name {}",
+        "user_entrypoint".red()
+    ));
+    assert_eq!(activation::error(&result), Some(error.as_str()), "{:?}", result);
+}
+
+#[test]
+pub fn create_out_of_gas() {
+    let endowment = U256::from(0.5e18);
+
+    // The child gets all but a 64th of what is left, which the loop burns through quickly.
+    let mut outcome = try_create_with_gas(OUT_OF_GAS_INIT_CODE.to_vec(), endowment, 100_000);
+
+    assert_eq!(outcome.returned, Address::ZERO);
+    assert!(outcome.revert_data.is_empty());
+
+    let factory = outcome.factory_info();
+    assert_eq!(factory.nonce, outcome.factory_nonce + 1);
+    assert_eq!(factory.balance, U256::from(FACTORY_BALANCE));
+    assert!(outcome.child_info().is_empty());
+}
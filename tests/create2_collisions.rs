@@ -0,0 +1,200 @@
+use alloy_sol_types::{sol, SolCall};
+use common::fixtures::base_state;
+use common::{evm_contract_init_code, DEPLOYER, EVM_CHILD_RUNTIME};
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::bytes::Bytes;
+use revm::primitives::{AccountInfo, Address, ExecutionResult, SpecId, TxEnv, TxKind, B256, U256};
+
+mod common;
+
+/// EVM child runtime: `selfdestruct(caller())`.
+const SELFDESTRUCT_RUNTIME: &[u8] = &[0x33, 0xff];
+
+sol!("tests/assets/abi/create_program.sol");
+
+const FACTORY_BALANCE: u64 = 1_000_000_000_000_000_000;
+
+struct Setup {
+    db: CacheDB<EmptyDB>,
+    spec: SpecId,
+    factory: Address,
+    salt: B256,
+}
+
+impl Setup {
+    fn new(spec: SpecId) -> Self {
+        let (mut db, fixtures) = base_state();
+
+        let factory = fixtures.create_program;
+        db.load_account(factory).unwrap().info.balance = U256::from(FACTORY_BALANCE);
+
+        Self {
+            db,
+            spec,
+            factory,
+            salt: B256::from(U256::from(1234)),
+        }
+    }
+
+    fn transact(&mut self, to: Address, data: Vec<u8>) -> ExecutionResult {
+        let evm = revm::Evm::builder()
+            .with_db(&mut self.db)
+            .with_spec_id(self.spec)
+            .modify_tx_env(|tx: &mut TxEnv| {
+                tx.caller = DEPLOYER;
+                tx.transact_to = TxKind::Call(to);
+                tx.data = data.into();
+                tx.gas_limit = 1e9 as u64;
+            });
+
+        evm.build().transact_commit().unwrap()
+    }
+
+    fn try_create2(&mut self, init_code: &[u8], endowment: U256) -> (Address, Bytes) {
+        let calldata = ICreateProgram::tryCreate2Call {
+            init_code: init_code.to_vec().into(),
+            salt: self.salt,
+            endowment,
+        };
+
+        let result = self.transact(self.factory, calldata.abi_encode());
+        assert!(result.is_success(), "{:?}", result);
+
+        let returned = ICreateProgram::tryCreate2Call::abi_decode_returns(result.output().unwrap(), true).unwrap();
+        (returned._0, returned._1.into())
+    }
+
+    fn target(&self, init_code: &[u8]) -> Address {
+        self.factory.create2_from_code(self.salt, init_code)
+    }
+
+    fn info(&mut self, address: Address) -> AccountInfo {
+        self.db.load_account(address).unwrap().info.clone()
+    }
+}
+
+#[test]
+pub fn create2_same_salt_collides() {
+    let mut setup = Setup::new(SpecId::LATEST);
+    let init_code = evm_contract_init_code(EVM_CHILD_RUNTIME.to_vec());
+    let endowment = U256::from(0.25e18);
+
+    let (child, _) = setup.try_create2(&init_code, endowment);
+    assert_eq!(child, setup.target(&init_code));
+
+    let child_before = setup.info(child);
+    let factory_before = setup.info(setup.factory);
+
+    // The Stylus program sees a failed deploy with no revert data.
+    let (created, revert_data) = setup.try_create2(&init_code, endowment);
+    assert_eq!(created, Address::ZERO);
+    assert!(revert_data.is_empty());
+
+    // Only the factory's nonce moves; the endowment stays with the factory.
+    let factory_after = setup.info(setup.factory);
+    assert_eq!(factory_after.nonce, factory_before.nonce + 1);
+    assert_eq!(factory_after.balance, factory_before.balance);
+    assert_eq!(setup.info(child), child_before);
+
+    // `create2` turns the same failure into an empty revert.
+    let result = setup.transact(
+        setup.factory,
+        ICreateProgram::create2Call {
+            init_code: init_code.into(),
+            salt: setup.salt,
+            endowment,
+        }
+        .abi_encode(),
+    );
+    match result {
+        ExecutionResult::Revert { output, .. } => assert!(output.is_empty()),
+        result => panic!("Expected revert: {:?}", result),
+    }
+}
+
+#[test]
+pub fn create2_onto_funded_address() {
+    let mut setup = Setup::new(SpecId::LATEST);
+    let init_code = evm_contract_init_code(EVM_CHILD_RUNTIME.to_vec());
+    let target = setup.target(&init_code);
+    let endowment = U256::from(0.25e18);
+    let prefund = U256::from(1000);
+
+    setup.db.load_account(target).unwrap().info.balance = prefund;
+
+    // Balance alone doesn't make an address taken; the child keeps it.
+    let (created, _) = setup.try_create2(&init_code, endowment);
+    assert_eq!(created, target);
+
+    let child = setup.info(target);
+    assert_eq!(child.balance, prefund + endowment);
+    assert_eq!(child.nonce, 1);
+    assert_eq!(child.code.unwrap().original_bytes().to_vec(), EVM_CHILD_RUNTIME);
+}
+
+#[test]
+pub fn create2_onto_address_with_nonce() {
+    let mut setup = Setup::new(SpecId::LATEST);
+    let init_code = evm_contract_init_code(EVM_CHILD_RUNTIME.to_vec());
+    let target = setup.target(&init_code);
+    let endowment = U256::from(0.25e18);
+
+    setup.db.load_account(target).unwrap().info.nonce = 1;
+    let target_before = setup.info(target);
+
+    let (created, revert_data) = setup.try_create2(&init_code, endowment);
+    assert_eq!(created, Address::ZERO);
+    assert!(revert_data.is_empty());
+
+    assert_eq!(setup.info(target), target_before);
+    assert_eq!(setup.info(setup.factory).balance, U256::from(FACTORY_BALANCE));
+}
+
+/// Deploys the self-destructing child, destroys it in a later transaction, then deploys it
+/// again with the same salt. Returns the original child and the address the second deploy
+/// reported.
+fn redeploy_after_selfdestruct(setup: &mut Setup) -> (Address, Address) {
+    let init_code = evm_contract_init_code(SELFDESTRUCT_RUNTIME.to_vec());
+    let endowment = U256::from(0.25e18);
+
+    let (child, _) = setup.try_create2(&init_code, endowment);
+    assert_eq!(child, setup.target(&init_code));
+
+    let deployer_balance = setup.info(DEPLOYER).balance;
+    let result = setup.transact(child, vec![]);
+    assert!(result.is_success(), "{:?}", result);
+
+    // Either way the balance goes to the beneficiary; gas is free in these tests.
+    assert_eq!(setup.info(child).balance, U256::ZERO);
+    assert_eq!(setup.info(DEPLOYER).balance, deployer_balance + endowment);
+
+    let (redeployed, _) = setup.try_create2(&init_code, U256::ZERO);
+    (child, redeployed)
+}
+
+#[test]
+pub fn create2_redeploys_after_selfdestruct_pre_cancun() {
+    let mut setup = Setup::new(SpecId::SHANGHAI);
+
+    let (child, redeployed) = redeploy_after_selfdestruct(&mut setup);
+
+    // Before Cancun SELFDESTRUCT clears the account, freeing the address.
+    assert_eq!(redeployed, child);
+    let info = setup.info(child);
+    assert_eq!(info.nonce, 1);
+    assert_eq!(info.code.unwrap().original_bytes().to_vec(), SELFDESTRUCT_RUNTIME);
+}
+
+#[test]
+pub fn create2_collides_after_selfdestruct_post_cancun() {
+    let mut setup = Setup::new(SpecId::CANCUN);
+
+    let (child, redeployed) = redeploy_after_selfdestruct(&mut setup);
+
+    // EIP-6780: SELFDESTRUCT outside the creating transaction only moves the balance, so the
+    // code stays and the redeploy collides.
+    assert_eq!(redeployed, Address::ZERO);
+    let info = setup.info(child);
+    assert_eq!(info.nonce, 1);
+    assert_eq!(info.code.unwrap().original_bytes().to_vec(), SELFDESTRUCT_RUNTIME);
+}
@@ -0,0 +1,186 @@
+use alloy_sol_types::{sol, SolCall};
+use common::fixtures::{base_state, Fixtures};
+use common::{evm_contract_init_code, wasm_contract_init_code, DEPLOYER, EVM_CHILD_RUNTIME};
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::bytes::Bytes;
+use revm::primitives::{Address, ExecutionResult, TxEnv, TxKind, B256, U256};
+use revm::STYLUS_MAGIC_BYTES;
+
+mod common;
+
+const EMIT_LOG_PROGRAM_BYTECODE: &[u8] = include_bytes!("assets/emit_log.wasm");
+
+sol!("tests/assets/abi/create_program.sol");
+sol!("tests/assets/abi/emit_log.sol");
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Vm {
+    Stylus,
+    Evm,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Scheme {
+    Create,
+    Create2(B256),
+}
+
+const VMS: [Vm; 2] = [Vm::Stylus, Vm::Evm];
+
+fn factory(fixtures: &Fixtures, vm: Vm) -> Address {
+    match vm {
+        Vm::Stylus => fixtures.create_program,
+        Vm::Evm => fixtures.create_test_evm,
+    }
+}
+
+fn child_init_code(vm: Vm) -> Vec<u8> {
+    match vm {
+        Vm::Stylus => wasm_contract_init_code(EMIT_LOG_PROGRAM_BYTECODE.to_vec()),
+        Vm::Evm => evm_contract_init_code(EVM_CHILD_RUNTIME.to_vec()),
+    }
+}
+
+fn transact(db: &mut CacheDB<EmptyDB>, to: Address, data: Vec<u8>) -> ExecutionResult {
+    let evm = revm::Evm::builder()
+        .with_db(db)
+        .modify_tx_env(|tx: &mut TxEnv| {
+            tx.caller = DEPLOYER;
+            tx.transact_to = TxKind::Call(to);
+            tx.data = data.into();
+            tx.gas_limit = 1e9 as u64;
+        });
+
+    evm.build().transact_commit().unwrap()
+}
+
+/// Calls the child the way its own tests would, checking that it is a working contract.
+fn exercise_child(db: &mut CacheDB<EmptyDB>, child: Address, vm: Vm) {
+    match vm {
+        Vm::Stylus => {
+            let calldata = IEmitLog::emitLogCall {
+                topics: vec![B256::repeat_byte(0x11)],
+                data: Bytes::from_static(b"hello"),
+            };
+            let result = transact(db, child, calldata.abi_encode());
+
+            assert!(result.is_success(), "{:?}", result);
+            assert_eq!(result.logs().len(), 1);
+            assert_eq!(result.logs()[0].address, child);
+        }
+        Vm::Evm => {
+            let result = transact(db, child, vec![]);
+
+            assert!(result.is_success(), "{:?}", result);
+            assert_eq!(
+                result.output().unwrap().to_vec(),
+                U256::from(42).to_be_bytes_vec()
+            );
+        }
+    }
+}
+
+fn create_test(factory_vm: Vm, child_vm: Vm, scheme: Scheme, endowment: U256) {
+    let (mut db, fixtures) = base_state();
+
+    let factory = factory(&fixtures, factory_vm);
+    db.load_account(factory).unwrap().info.balance = endowment;
+
+    let init_code = child_init_code(child_vm);
+    let factory_nonce = db.load_account(factory).unwrap().info.nonce;
+
+    let (expected_address, calldata) = match scheme {
+        Scheme::Create => (
+            factory.create(factory_nonce),
+            ICreateProgram::createCall {
+                init_code: init_code.clone().into(),
+                endowment,
+            }
+            .abi_encode(),
+        ),
+        Scheme::Create2(salt) => (
+            factory.create2_from_code(salt, &init_code),
+            ICreateProgram::create2Call {
+                init_code: init_code.clone().into(),
+                salt,
+                endowment,
+            }
+            .abi_encode(),
+        ),
+    };
+
+    let context = format!("{:?} factory, {:?} child, {:?}", factory_vm, child_vm, scheme);
+
+    let result = transact(&mut db, factory, calldata);
+    assert!(result.is_success(), "{}: {:?}", context, result);
+
+    let returned = ICreateProgram::createCall::abi_decode_returns(result.output().unwrap(), true)
+        .unwrap()
+        ._0;
+    assert_eq!(returned, expected_address, "{}", context);
+
+    let factory_info = db.load_account(factory).unwrap().info.clone();
+    assert_eq!(factory_info.nonce, factory_nonce + 1, "{}", context);
+    assert_eq!(factory_info.balance, U256::ZERO, "{}", context);
+
+    let child_info = db.load_account(expected_address).unwrap().info.clone();
+    assert_eq!(child_info.balance, endowment, "{}", context);
+    assert_eq!(child_info.nonce, 1, "{}", context);
+
+    let code = child_info.code.unwrap().original_bytes();
+    match child_vm {
+        Vm::Stylus => assert_eq!(
+            code.to_vec(),
+            [
+                Bytes::from(STYLUS_MAGIC_BYTES),
+                Bytes::from(EMIT_LOG_PROGRAM_BYTECODE),
+            ]
+            .concat(),
+            "{}",
+            context
+        ),
+        Vm::Evm => assert_eq!(code.to_vec(), EVM_CHILD_RUNTIME, "{}", context),
+    }
+
+    exercise_child(&mut db, expected_address, child_vm);
+}
+
+#[test]
+pub fn create_matrix() {
+    for factory_vm in VMS {
+        for child_vm in VMS {
+            create_test(factory_vm, child_vm, Scheme::Create, U256::ZERO);
+        }
+    }
+}
+
+#[test]
+pub fn create_matrix_with_endowment() {
+    for factory_vm in VMS {
+        for child_vm in VMS {
+            create_test(factory_vm, child_vm, Scheme::Create, U256::from(0.5e18));
+        }
+    }
+}
+
+#[test]
+pub fn create2_matrix() {
+    let salt = B256::from(U256::from(1234));
+
+    for factory_vm in VMS {
+        for child_vm in VMS {
+            create_test(factory_vm, child_vm, Scheme::Create2(salt), U256::ZERO);
+        }
+    }
+}
+
+#[test]
+pub fn create2_matrix_with_endowment() {
+    let salt = B256::from(U256::from(1234));
+
+    for factory_vm in VMS {
+        for child_vm in VMS {
+            create_test(factory_vm, child_vm, Scheme::Create2(salt), U256::from(0.5e18));
+        }
+    }
+}
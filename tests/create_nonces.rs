@@ -0,0 +1,165 @@
+use alloy_sol_types::{sol, SolCall};
+use common::fixtures::base_state;
+use common::{
+    deploy_wasm, evm_contract_init_code, next_create_address, reverting_init_code, DEPLOYER,
+    EVM_CHILD_RUNTIME,
+};
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::bytes::Bytes;
+use revm::primitives::{keccak256, Address, ExecutionResult, TxEnv, TxKind, B256, U256};
+
+mod common;
+
+const CREATE_PROGRAM_BYTECODE: &[u8] = include_bytes!("assets/create_program.wasm");
+
+sol!("tests/assets/abi/create_program.sol");
+
+#[derive(Clone, Copy, Debug)]
+enum Vm {
+    Stylus,
+    Evm,
+}
+
+struct Factory {
+    db: CacheDB<EmptyDB>,
+    vm: Vm,
+    address: Address,
+}
+
+impl Factory {
+    fn new(vm: Vm, balance: U256) -> Self {
+        let (mut db, fixtures) = base_state();
+
+        let address = match vm {
+            Vm::Stylus => fixtures.create_program,
+            Vm::Evm => fixtures.create_test_evm,
+        };
+        db.load_account(address).unwrap().info.balance = balance;
+
+        Self { db, vm, address }
+    }
+
+    fn nonce(&mut self) -> u64 {
+        self.db.load_account(self.address).unwrap().info.nonce
+    }
+
+    fn transact(&mut self, data: Vec<u8>) -> ExecutionResult {
+        let factory = self.address;
+        let evm = revm::Evm::builder()
+            .with_db(&mut self.db)
+            .modify_tx_env(|tx: &mut TxEnv| {
+                tx.caller = DEPLOYER;
+                tx.transact_to = TxKind::Call(factory);
+                tx.data = data.into();
+                tx.gas_limit = 1e9 as u64;
+            });
+
+        evm.build().transact_commit().unwrap()
+    }
+
+    /// Runs `tryCreate` or `tryCreate2`, returning the created address (zero on failure)
+    /// and the child's revert data.
+    fn try_create(&mut self, init_code: Vec<u8>, salt: Option<B256>, endowment: U256) -> (Address, Bytes) {
+        let calldata = match salt {
+            None => ICreateProgram::tryCreateCall {
+                init_code: init_code.into(),
+                endowment,
+            }
+            .abi_encode(),
+            Some(salt) => ICreateProgram::tryCreate2Call {
+                init_code: init_code.into(),
+                salt,
+                endowment,
+            }
+            .abi_encode(),
+        };
+
+        let result = self.transact(calldata);
+        assert!(result.is_success(), "{:?}: {:?}", self.vm, result);
+
+        let returned = ICreateProgram::tryCreateCall::abi_decode_returns(result.output().unwrap(), true).unwrap();
+        (returned._0, returned._1.into())
+    }
+}
+
+fn nonce_sequence(vm: Vm) {
+    let mut factory = Factory::new(vm, U256::from(1e18));
+    let child_init_code = evm_contract_init_code(EVM_CHILD_RUNTIME.to_vec());
+    let salt = B256::from(U256::from(1234));
+
+    // A successful CREATE lands at the predicted address and spends a nonce.
+    let nonce = factory.nonce();
+    let expected = next_create_address(&factory.db, factory.address);
+    let (created, _) = factory.try_create(child_init_code.clone(), None, U256::ZERO);
+    assert_eq!(created, expected, "{:?}", vm);
+    assert_eq!(factory.nonce(), nonce + 1, "{:?}", vm);
+
+    // Reverting init code still spends the nonce, so the prediction moves on.
+    let skipped = next_create_address(&factory.db, factory.address);
+    let (created, revert_data) = factory.try_create(reverting_init_code(keccak256("reverted")), None, U256::ZERO);
+    assert_eq!(created, Address::ZERO, "{:?}", vm);
+    assert_eq!(revert_data, Bytes::from(keccak256("reverted").to_vec()), "{:?}", vm);
+    assert_eq!(factory.nonce(), nonce + 2, "{:?}", vm);
+    assert_ne!(next_create_address(&factory.db, factory.address), skipped, "{:?}", vm);
+    assert!(factory.db.load_account(skipped).unwrap().info.is_empty(), "{:?}", vm);
+
+    // CREATE2 doesn't derive its address from the nonce, but spends one all the same.
+    let expected = factory.address.create2_from_code(salt, &child_init_code);
+    let (created, _) = factory.try_create(child_init_code.clone(), Some(salt), U256::ZERO);
+    assert_eq!(created, expected, "{:?}", vm);
+    assert_eq!(factory.nonce(), nonce + 3, "{:?}", vm);
+
+    // Colliding with the existing child fails the creation after the nonce is spent.
+    let (created, revert_data) = factory.try_create(child_init_code.clone(), Some(salt), U256::ZERO);
+    assert_eq!(created, Address::ZERO, "{:?}", vm);
+    assert!(revert_data.is_empty(), "{:?}", vm);
+    assert_eq!(factory.nonce(), nonce + 4, "{:?}", vm);
+
+    // An endowment the factory can't afford fails before the nonce is touched.
+    let (created, _) = factory.try_create(child_init_code.clone(), None, U256::from(2e18));
+    assert_eq!(created, Address::ZERO, "{:?}", vm);
+    assert_eq!(factory.nonce(), nonce + 4, "{:?}", vm);
+
+    // Reverting the whole call rolls the nonce back with everything else.
+    let result = factory.transact(
+        ICreateProgram::createCall {
+            init_code: reverting_init_code(keccak256("reverted")).into(),
+            endowment: U256::ZERO,
+        }
+        .abi_encode(),
+    );
+    assert!(matches!(result, ExecutionResult::Revert { .. }), "{:?}: {:?}", vm, result);
+    assert_eq!(factory.nonce(), nonce + 4, "{:?}", vm);
+
+    // The prediction still holds after all of the above.
+    let expected = next_create_address(&factory.db, factory.address);
+    let (created, _) = factory.try_create(child_init_code, None, U256::ZERO);
+    assert_eq!(created, expected, "{:?}", vm);
+    assert_eq!(factory.nonce(), nonce + 5, "{:?}", vm);
+}
+
+#[test]
+pub fn stylus_factory_nonce_sequence() {
+    nonce_sequence(Vm::Stylus);
+}
+
+#[test]
+pub fn evm_factory_nonce_sequence() {
+    nonce_sequence(Vm::Evm);
+}
+
+#[test]
+pub fn next_create_address_follows_deployer_nonce() {
+    let (mut db, _) = base_state();
+    let nonce = db.load_account(DEPLOYER).unwrap().info.nonce;
+
+    for _ in 0..3 {
+        let expected = next_create_address(&db, DEPLOYER);
+        let deployed = deploy_wasm(&mut db, CREATE_PROGRAM_BYTECODE.to_vec(), DEPLOYER);
+
+        assert_eq!(deployed, expected);
+        assert!(db.accounts.get(&deployed).unwrap().info.code.is_some());
+    }
+
+    assert_eq!(next_create_address(&db, DEPLOYER), DEPLOYER.create(nonce + 3));
+}
@@ -0,0 +1,243 @@
+use alloy_signer_local::PrivateKeySigner;
+use alloy_sol_types::{sol, SolCall};
+use common::fixtures::{base_state, Fixtures};
+use common::multicall::{call, multicall, Multicaller};
+use common::signing::{sign_authorization, test_key, transact_raw, SignedTx};
+use common::DEPLOYER;
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{
+    keccak256, AccountInfo, Address, Bytecode, Bytes, ExecutionResult, TxEnv, TxKind, B256, U256,
+};
+
+mod common;
+
+sol!("tests/assets/abi/hostio_probe.sol");
+sol!("tests/assets/abi/test_program.sol");
+
+/// Prefix of the code EIP-7702 installs in a delegating account, followed by the delegate.
+const DELEGATION_PREFIX: [u8; 3] = [0xef, 0x01, 0x00];
+
+struct Setup {
+    db: CacheDB<EmptyDB>,
+    fixtures: Fixtures,
+    /// Pays for the EIP-7702 transactions.
+    sponsor: PrivateKeySigner,
+    /// The EOA that delegates its code.
+    authority: PrivateKeySigner,
+}
+
+impl Setup {
+    fn new() -> Self {
+        let (mut db, fixtures) = base_state();
+        let sponsor = test_key(0);
+
+        let mut info = AccountInfo::default();
+        info.balance = U256::from(1e18);
+        db.insert_account_info(sponsor.address(), info);
+
+        Self {
+            db,
+            fixtures,
+            sponsor,
+            authority: test_key(1),
+        }
+    }
+
+    /// Has the authority delegate its code to `delegate`, in a transaction the sponsor
+    /// sends to an unrelated account.
+    fn delegate(&mut self, delegate: Address) {
+        let sponsor_nonce = self
+            .db
+            .load_account(self.sponsor.address())
+            .unwrap()
+            .info
+            .nonce;
+        let authority_nonce = self
+            .db
+            .load_account(self.authority.address())
+            .unwrap()
+            .info
+            .nonce;
+
+        let tx = SignedTx::eip7702(test_key(2).address())
+            .nonce(sponsor_nonce)
+            .authorization(sign_authorization(
+                &self.authority,
+                delegate,
+                authority_nonce,
+            ));
+        let result = transact_raw(&mut self.db, &tx.encode(&self.sponsor)).unwrap();
+        assert!(result.is_success(), "{:?}", result);
+
+        let code = self
+            .db
+            .load_account(self.authority.address())
+            .unwrap()
+            .info
+            .code
+            .clone();
+        assert_eq!(code, Some(Bytecode::new_eip7702(delegate)));
+    }
+
+    fn transact(&mut self, to: Address, data: Vec<u8>) -> ExecutionResult {
+        let evm = revm::Evm::builder()
+            .with_db(&mut self.db)
+            .modify_tx_env(|tx: &mut TxEnv| {
+                tx.caller = DEPLOYER;
+                tx.transact_to = TxKind::Call(to);
+                tx.data = data.into();
+                tx.gas_limit = 1_000_000_000;
+            });
+
+        evm.build().transact_commit().unwrap()
+    }
+
+    fn storage(&mut self, account: Address, slot: B256) -> U256 {
+        let account = self.db.load_account(account).unwrap();
+        account
+            .storage
+            .get(&slot.into())
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+fn set_storage() -> ITestProgram::setStorageCall {
+    ITestProgram::setStorageCall {
+        key: keccak256("delegated-slot"),
+        value: keccak256("delegated-value"),
+    }
+}
+
+#[test]
+pub fn delegated_eoa_runs_program_in_its_own_context() {
+    let mut setup = Setup::new();
+    let authority = setup.authority.address();
+    setup.delegate(setup.fixtures.hostio_probe);
+
+    let result = setup.transact(authority, IHostioProbe::contractAddressCall {}.abi_encode());
+    assert!(result.is_success(), "{:?}", result);
+    let address =
+        IHostioProbe::contractAddressCall::abi_decode_returns(result.output().unwrap(), true)
+            .unwrap()
+            ._0;
+    assert_eq!(address, authority);
+
+    let result = setup.transact(authority, IHostioProbe::contextCall {}.abi_encode());
+    assert!(result.is_success(), "{:?}", result);
+    let context =
+        IHostioProbe::contextCall::abi_decode_returns(result.output().unwrap(), true).unwrap();
+    assert_eq!(context._0, DEPLOYER);
+}
+
+#[test]
+pub fn delegated_storage_writes_land_in_eoa() {
+    let mut setup = Setup::new();
+    let authority = setup.authority.address();
+    let program = setup.fixtures.test_program;
+    setup.delegate(program);
+
+    let call = set_storage();
+    let result = setup.transact(authority, call.abi_encode());
+    assert!(result.is_success(), "{:?}", result);
+
+    assert_eq!(
+        setup.storage(authority, call.key),
+        U256::from_be_bytes(call.value.0)
+    );
+    assert_eq!(setup.storage(program, call.key), U256::ZERO);
+
+    let result = setup.transact(
+        authority,
+        ITestProgram::getStorageCall { key: call.key }.abi_encode(),
+    );
+    assert!(result.is_success(), "{:?}", result);
+    let stored = ITestProgram::getStorageCall::abi_decode_returns(result.output().unwrap(), true)
+        .unwrap()
+        ._0;
+    assert_eq!(stored, call.value);
+}
+
+#[test]
+pub fn account_code_of_delegated_eoa_is_designator() {
+    let mut setup = Setup::new();
+    let authority = setup.authority.address();
+    let program = setup.fixtures.test_program;
+    setup.delegate(setup.fixtures.hostio_probe);
+
+    let result = setup.transact(
+        program,
+        ITestProgram::accountCodeCall { account: authority }.abi_encode(),
+    );
+    assert!(result.is_success(), "{:?}", result);
+    let code = ITestProgram::accountCodeCall::abi_decode_returns(result.output().unwrap(), true)
+        .unwrap()
+        ._0;
+
+    let designator = [
+        &DELEGATION_PREFIX[..],
+        setup.fixtures.hostio_probe.as_slice(),
+    ]
+    .concat();
+    assert_eq!(code, Bytes::from(designator));
+}
+
+#[test]
+pub fn eoa_delegated_to_evm_multicall_reaches_stylus() {
+    let mut setup = Setup::new();
+    let authority = setup.authority.address();
+    let program = setup.fixtures.test_program;
+    setup.delegate(setup.fixtures.multicall_evm);
+    let set = set_storage();
+
+    // A plain call from the delegated EOA writes to the program's storage.
+    let calldata = multicall(vec![call(Multicaller::CallType::CALL, program, set.abi_encode())]);
+    let result = setup.transact(authority, calldata);
+    assert!(result.is_success(), "{:?}", result);
+    assert_eq!(
+        setup.storage(program, set.key),
+        U256::from_be_bytes(set.value.0)
+    );
+    assert_eq!(setup.storage(authority, set.key), U256::ZERO);
+
+    // Delegate-calling the program from the EOA runs it against the EOA's storage.
+    let set = ITestProgram::setStorageCall {
+        key: keccak256("delegatecall-slot"),
+        value: keccak256("delegatecall-value"),
+    };
+    let calldata = multicall(vec![call(
+        Multicaller::CallType::DELEGATECALL,
+        program,
+        set.abi_encode(),
+    )]);
+    let result = setup.transact(authority, calldata);
+    assert!(result.is_success(), "{:?}", result);
+    assert_eq!(
+        setup.storage(authority, set.key),
+        U256::from_be_bytes(set.value.0)
+    );
+    assert_eq!(setup.storage(program, set.key), U256::ZERO);
+}
+
+#[test]
+pub fn stylus_multicall_calls_delegated_eoa() {
+    let mut setup = Setup::new();
+    let authority = setup.authority.address();
+    let program = setup.fixtures.test_program;
+    setup.delegate(program);
+    let set = set_storage();
+
+    let calldata = multicall(vec![call(Multicaller::CallType::CALL, authority, set.abi_encode())]);
+    let result = setup.transact(setup.fixtures.multicall, calldata);
+    assert!(result.is_success(), "{:?}", result);
+
+    assert_eq!(
+        setup.storage(authority, set.key),
+        U256::from_be_bytes(set.value.0)
+    );
+    assert_eq!(setup.storage(program, set.key), U256::ZERO);
+    assert_eq!(
+        setup.storage(setup.fixtures.multicall, set.key),
+        U256::ZERO
+    );
+}
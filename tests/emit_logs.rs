@@ -1,25 +1,24 @@
-use alloy_sol_types::{sol, SolCall, SolEvent};
+use alloy_sol_types::{sol, Revert, SolCall, SolError, SolEvent};
 
+use common::fixtures::{base_state, Fixtures};
+use common::multicall::{call, multicall, Multicaller};
+use common::DEPLOYER;
 use revm::db::{CacheDB, EmptyDB};
 use revm::primitives::alloy_primitives::IntoLogData;
-use revm::primitives::{address, TxEnv, TxKind, U256};
+use revm::primitives::{
+    address, keccak256, Address, Bytes, ExecutionResult, Log, TxEnv, TxKind, B256, U256,
+};
 
 mod common;
 
 const EMIT_LOG_PROGRAM_BYTECODE: &[u8] = include_bytes!("assets/emit_log.wasm");
 
-sol! {
-    contract StorageTest {
-        event HelloFromStylus(address indexed some_address, uint256 some_number, bytes some_data);
+sol!("tests/assets/abi/emit_log.sol");
 
-        function emitLog(bytes32[] memory topics, bytes memory data);
-    }
- 
+sol! {
+    event HelloFromStylus(address indexed some_address, uint256 some_number, bytes some_data);
 }
 
-use crate::StorageTest::HelloFromStylus;
-
-
 #[test]
 pub fn emit_logs() {
     let mut db = CacheDB::new(EmptyDB::new());
@@ -36,7 +35,7 @@ pub fn emit_logs() {
 
     let expected = expected_log.clone().into_log_data();
 
-    let calldata = StorageTest::emitLogCall {
+    let calldata = IEmitLog::emitLogCall {
         topics: expected.topics().into(),
         data: expected.data.clone(),
     };
@@ -62,3 +61,264 @@ pub fn emit_logs() {
     assert_eq!(log.some_number, expected_log.some_number);
     assert_eq!(log.some_data, expected_log.some_data);
 }
+
+sol!("tests/assets/abi/recursive.sol");
+
+struct Setup {
+    db: CacheDB<EmptyDB>,
+    fixtures: Fixtures,
+}
+
+impl Setup {
+    fn new() -> Self {
+        let (db, fixtures) = base_state();
+        Self { db, fixtures }
+    }
+
+    /// The Stylus emitter and its EVM counterpart.
+    fn emitters(&self) -> [Address; 2] {
+        [self.fixtures.emit_log, self.fixtures.emit_log_evm]
+    }
+
+    fn transact(&mut self, to: Address, data: Vec<u8>) -> ExecutionResult {
+        let mut evm = revm::Evm::builder()
+            .with_db(&mut self.db)
+            .modify_tx_env(|tx: &mut TxEnv| {
+                tx.caller = DEPLOYER;
+                tx.transact_to = TxKind::Call(to);
+                tx.data = data.into();
+                tx.gas_limit = 1_000_000_000;
+            })
+            .build();
+
+        evm.transact().unwrap().result
+    }
+}
+
+/// A log identifiable by its label: every topic and the data are derived from it.
+fn labelled(label: &str, topics: usize) -> (Vec<B256>, Bytes) {
+    let topics = (0..topics)
+        .map(|i| keccak256(format!("{}-topic-{}", label, i)))
+        .collect();
+    (topics, Bytes::copy_from_slice(label.as_bytes()))
+}
+
+/// The buffer the `emit_log` hostio takes: every topic, then the data.
+fn raw_buffer(topics: &[B256], data: &[u8]) -> Bytes {
+    topics.iter().flat_map(|topic| topic.0).chain(data.iter().copied()).collect()
+}
+
+fn emit_log(label: &str) -> Vec<u8> {
+    let (topics, data) = labelled(label, 1);
+    IEmitLog::emitLogCall { topics, data }.abi_encode()
+}
+
+fn revert_output(result: &ExecutionResult) -> Option<&[u8]> {
+    match result {
+        ExecutionResult::Revert { output, .. } => Some(output),
+        _ => None,
+    }
+}
+
+/// The labels of `logs`, with the account that emitted each.
+fn emitted(logs: &[Log]) -> Vec<(Address, String)> {
+    logs.iter()
+        .map(|log| (log.address, String::from_utf8(log.data.data.to_vec()).unwrap()))
+        .collect()
+}
+
+#[test]
+pub fn logs_with_up_to_four_topics() {
+    let mut setup = Setup::new();
+
+    for emitter in setup.emitters() {
+        for count in 0..=4 {
+            let (topics, data) = labelled("topics", count);
+            let calldata = IEmitLog::emitLogCall { topics: topics.clone(), data: data.clone() };
+
+            let result = setup.transact(emitter, calldata.abi_encode());
+            assert!(result.is_success(), "{} topics from {}: {:?}", count, emitter, result);
+
+            let logs = result.logs();
+            assert_eq!(logs.len(), 1);
+            assert_eq!(logs[0].address, emitter);
+            assert_eq!(logs[0].topics(), topics.as_slice());
+            assert_eq!(logs[0].data.data, data);
+        }
+    }
+}
+
+#[test]
+pub fn raw_logs_split_topics_from_data() {
+    let mut setup = Setup::new();
+    let (topics, data) = labelled("raw", 3);
+    let buffer = raw_buffer(&topics, &data);
+
+    for emitter in setup.emitters() {
+        let calldata = IEmitLog::emitRawLogCall { topics: 3, buffer: buffer.clone() };
+        let result = setup.transact(emitter, calldata.abi_encode());
+        assert!(result.is_success(), "{}: {:?}", emitter, result);
+
+        let logs = result.logs();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].topics(), topics.as_slice());
+        assert_eq!(logs[0].data.data, data);
+    }
+}
+
+#[test]
+pub fn more_than_four_topics_are_rejected() {
+    let mut setup = Setup::new();
+    let (topics, data) = labelled("too-many", 5);
+    let Fixtures { emit_log, emit_log_evm, .. } = setup.fixtures;
+    let solidity_error = Revert { reason: "too many topics".to_string() }.abi_encode();
+
+    // The SDK refuses with a plain message, solc with an `Error(string)`.
+    for (emitter, output) in [
+        (emit_log, b"too many topics".to_vec()),
+        (emit_log_evm, solidity_error.clone()),
+    ] {
+        let calldata = IEmitLog::emitLogCall { topics: topics.clone(), data: data.clone() };
+        let result = setup.transact(emitter, calldata.abi_encode());
+        assert_eq!(revert_output(&result), Some(output.as_slice()), "{}: {:?}", emitter, result);
+        assert!(result.logs().is_empty());
+    }
+
+    // Past the SDK, straight to the hostio, which fails the program with Nitro's own error.
+    // The buffer holds every topic, so the EVM emitter gets as far as `emitLog`'s check.
+    for (emitter, output) in [
+        (emit_log, b"bad topic data".to_vec()),
+        (emit_log_evm, solidity_error),
+    ] {
+        let calldata = IEmitLog::emitRawLogCall { topics: 5, buffer: raw_buffer(&topics, &data) };
+        let result = setup.transact(emitter, calldata.abi_encode());
+        assert_eq!(revert_output(&result), Some(output.as_slice()), "{}: {:?}", emitter, result);
+        assert!(result.logs().is_empty());
+    }
+}
+
+#[test]
+pub fn raw_log_buffer_must_hold_every_topic() {
+    let mut setup = Setup::new();
+    let Fixtures { emit_log, emit_log_evm, .. } = setup.fixtures;
+    let solidity_error = Revert { reason: "bad topic data".to_string() }.abi_encode();
+
+    for (emitter, output) in [
+        (emit_log, b"bad topic data".to_vec()),
+        (emit_log_evm, solidity_error),
+    ] {
+        let calldata = IEmitLog::emitRawLogCall { topics: 2, buffer: vec![0u8; 63].into() };
+        let result = setup.transact(emitter, calldata.abi_encode());
+        assert_eq!(revert_output(&result), Some(output.as_slice()), "{}: {:?}", emitter, result);
+        assert!(result.logs().is_empty());
+    }
+}
+
+#[test]
+pub fn large_log_data() {
+    let mut setup = Setup::new();
+    let data: Bytes = (0..64 * 1024).map(|i| i as u8).collect::<Vec<u8>>().into();
+    let topics = vec![keccak256("large")];
+
+    for emitter in setup.emitters() {
+        let calldata = IEmitLog::emitLogCall { topics: topics.clone(), data: data.clone() };
+        let result = setup.transact(emitter, calldata.abi_encode());
+        assert!(result.is_success(), "{}: {:?}", emitter, result);
+
+        let logs = result.logs();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].data.data, data);
+    }
+}
+
+#[test]
+pub fn delegatecall_logs_come_from_caller() {
+    let mut setup = Setup::new();
+    let callers = [setup.fixtures.multicall, setup.fixtures.multicall_evm];
+
+    for caller in callers {
+        for emitter in setup.emitters() {
+            let calldata = multicall(vec![call(
+                Multicaller::CallType::DELEGATECALL,
+                emitter,
+                emit_log("delegated"),
+            )]);
+            let result = setup.transact(caller, calldata);
+            assert!(result.is_success(), "{} -> {}: {:?}", caller, emitter, result);
+
+            assert_eq!(emitted(result.logs()), vec![(caller, "delegated".to_string())]);
+        }
+    }
+}
+
+#[test]
+pub fn reverted_frames_drop_their_logs() {
+    let mut setup = Setup::new();
+    let Fixtures { emit_log: stylus, emit_log_evm: evm, recursive, recursive_evm, .. } =
+        setup.fixtures;
+
+    let emit_then_revert = |label: &str| {
+        let (topics, data) = labelled(label, 1);
+        IEmitLog::emitThenRevertCall { topics, data }.abi_encode()
+    };
+    let try_call = |target: Address, data: Vec<u8>| {
+        IRecursive::tryCallCall { target, data: data.into() }.abi_encode()
+    };
+
+    for caller in [setup.fixtures.multicall, setup.fixtures.multicall_evm] {
+        let calldata = multicall(vec![
+            call(Multicaller::CallType::CALL, stylus, emit_log("kept-stylus")),
+            call(
+                Multicaller::CallType::CALL,
+                recursive,
+                try_call(stylus, emit_then_revert("dropped-stylus")),
+            ),
+            call(
+                Multicaller::CallType::CALL,
+                recursive_evm,
+                try_call(evm, emit_then_revert("dropped-evm")),
+            ),
+            call(Multicaller::CallType::CALL, evm, emit_log("kept-evm")),
+        ]);
+        let result = setup.transact(caller, calldata);
+        assert!(result.is_success(), "{}: {:?}", caller, result);
+
+        assert_eq!(
+            emitted(result.logs()),
+            vec![(stylus, "kept-stylus".to_string()), (evm, "kept-evm".to_string())]
+        );
+    }
+}
+
+#[test]
+pub fn logs_keep_order_across_nested_frames() {
+    let mut setup = Setup::new();
+    let Fixtures { emit_log: stylus, emit_log_evm: evm, .. } = setup.fixtures;
+    let multicalls = [setup.fixtures.multicall, setup.fixtures.multicall_evm];
+
+    // Each ordering nests the other VM's multicall inside one of its own.
+    for (outer, inner) in [(multicalls[0], multicalls[1]), (multicalls[1], multicalls[0])] {
+        let innermost = multicall(vec![call(Multicaller::CallType::CALL, evm, emit_log("4"))]);
+        let nested = multicall(vec![
+            call(Multicaller::CallType::CALL, stylus, emit_log("3")),
+            call(Multicaller::CallType::CALL, outer, innermost),
+            call(Multicaller::CallType::CALL, stylus, emit_log("5")),
+        ]);
+        let calldata = multicall(vec![
+            call(Multicaller::CallType::CALL, stylus, emit_log("1")),
+            call(Multicaller::CallType::CALL, evm, emit_log("2")),
+            call(Multicaller::CallType::CALL, inner, nested),
+            call(Multicaller::CallType::CALL, evm, emit_log("6")),
+        ]);
+
+        let result = setup.transact(outer, calldata);
+        assert!(result.is_success(), "{}: {:?}", outer, result);
+
+        let expected = [stylus, evm, stylus, evm, stylus, evm]
+            .into_iter()
+            .zip(1..)
+            .map(|(address, label)| (address, label.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(emitted(result.logs()), expected);
+    }
+}
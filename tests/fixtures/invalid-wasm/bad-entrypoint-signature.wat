@@ -0,0 +1,5 @@
+;; `user_entrypoint` must have type (i32) -> i32.
+(module
+    (memory (export "memory") 1 1)
+    (func (export "user_entrypoint") (param $args_len i64) (result i32)
+        i32.const 0))
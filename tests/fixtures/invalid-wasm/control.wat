@@ -0,0 +1,5 @@
+;; The smallest valid Stylus program. Every other fixture breaks exactly one rule of it.
+(module
+    (memory (export "memory") 1 1)
+    (func (export "user_entrypoint") (param $args_len i32) (result i32)
+        i32.const 0))
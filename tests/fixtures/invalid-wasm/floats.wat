@@ -0,0 +1,9 @@
+;; Stylus programs may not use floating point instructions.
+(module
+    (memory (export "memory") 1 1)
+    (func (export "user_entrypoint") (param $args_len i32) (result i32)
+        f32.const 1
+        f32.const 2
+        f32.add
+        drop
+        i32.const 0))
@@ -0,0 +1,5 @@
+;; The initial memory may not exceed Stylus's 128 page limit.
+(module
+    (memory (export "memory") 129)
+    (func (export "user_entrypoint") (param $args_len i32) (result i32)
+        i32.const 0))
@@ -0,0 +1,5 @@
+;; Programs must export `user_entrypoint`.
+(module
+    (memory (export "memory") 1 1)
+    (func (export "main") (param $args_len i32) (result i32)
+        i32.const 0))
@@ -0,0 +1,5 @@
+;; Programs must export their memory as `memory`.
+(module
+    (memory 1 1)
+    (func (export "user_entrypoint") (param $args_len i32) (result i32)
+        i32.const 0))
@@ -0,0 +1,6 @@
+;; Data segments must fit within the initial memory.
+(module
+    (memory (export "memory") 1 1)
+    (data (i32.const 65520) "0123456789abcdef0123456789abcdef")
+    (func (export "user_entrypoint") (param $args_len i32) (result i32)
+        i32.const 0))
@@ -0,0 +1,7 @@
+;; Start functions are not allowed.
+(module
+    (memory (export "memory") 1 1)
+    (func $init)
+    (start $init)
+    (func (export "user_entrypoint") (param $args_len i32) (result i32)
+        i32.const 0))
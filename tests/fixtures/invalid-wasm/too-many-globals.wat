@@ -0,0 +1,2056 @@
+;; One global over the 32768 Nitro allows. At five bytes each the globals alone take more
+;; than MAX_WASM_SIZE, so the program has to be deployed compressed, and activation stops
+;; at the size check before it counts them.
+(module
+    (memory (export "memory") 1 1)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0) (global i32 i32.const 0)
+    (global i32 i32.const 0)
+    (func (export "user_entrypoint") (param $args_len i32) (result i32)
+        i32.const 0))
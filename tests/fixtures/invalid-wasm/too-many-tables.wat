@@ -0,0 +1,7 @@
+;; Programs may declare at most one table.
+(module
+    (memory (export "memory") 1 1)
+    (table 1 funcref)
+    (table 1 funcref)
+    (func (export "user_entrypoint") (param $args_len i32) (result i32)
+        i32.const 0))
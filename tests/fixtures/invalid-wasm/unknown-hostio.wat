@@ -0,0 +1,8 @@
+;; Imports must name a hostio that exists.
+(module
+    (import "vm_hooks" "not_a_hostio" (func $not_a_hostio (param i32)))
+    (memory (export "memory") 1 1)
+    (func (export "user_entrypoint") (param $args_len i32) (result i32)
+        i32.const 0
+        call $not_a_hostio
+        i32.const 0))
@@ -0,0 +1,14 @@
+;; Returns the value in its storage slot 0.
+(module
+    (import "vm_hooks" "storage_load_bytes32" (func $storage_load_bytes32 (param i32 i32)))
+    (import "vm_hooks" "write_result" (func $write_result (param i32 i32)))
+    (memory (export "memory") 1 1)
+    (func (export "user_entrypoint") (param $args_len i32) (result i32)
+        ;; The key is the zero word at 0; the value is loaded to 32.
+        i32.const 0
+        i32.const 32
+        call $storage_load_bytes32
+        i32.const 32
+        i32.const 32
+        call $write_result
+        i32.const 0))
@@ -0,0 +1,165 @@
+use arbutil::Color;
+use common::{
+    activation, compress_wasm, setup_simple_test, try_deploy_wasm, DEPLOYER, STYLUS_MAX_CODE_SIZE,
+};
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{ExecutionResult, TxEnv, TxKind};
+use revm::STYLUS_MAGIC_BYTES;
+
+mod common;
+
+fn wat(source: &str) -> Vec<u8> {
+    wat::parse_str(source).unwrap()
+}
+
+/// Deploys and calls `wasm`. Deploying only stores the code, so it must succeed whatever the
+/// WASM holds; Nitro's checks run when the call activates the program.
+fn run(wasm: Vec<u8>) -> ExecutionResult {
+    let mut db = CacheDB::new(EmptyDB::new());
+    setup_simple_test(&mut db);
+
+    let (program, deployment) = try_deploy_wasm(&mut db, wasm, DEPLOYER);
+    let deployment = deployment.expect("Deployment transaction was rejected");
+    assert!(deployment.is_success(), "{:?}", deployment);
+
+    let evm = revm::Evm::builder()
+        .with_db(&mut db)
+        .modify_tx_env(|tx: &mut TxEnv| {
+            tx.caller = DEPLOYER;
+            tx.transact_to = TxKind::Call(program);
+            tx.gas_limit = 1_000_000_000;
+        });
+
+    evm.build().transact_commit().unwrap()
+}
+
+fn assert_rejected(wasm: Vec<u8>, error: String) {
+    let result = run(wasm);
+    assert_eq!(activation::error(&result), Some(error.as_str()), "{:?}", result);
+}
+
+#[test]
+pub fn control_program_is_valid() {
+    let result = run(wat(include_str!("fixtures/invalid-wasm/control.wat")));
+    assert!(result.is_success(), "{:?}", result);
+}
+
+#[test]
+pub fn bad_magic_is_rejected() {
+    assert_rejected(
+        vec![0x00, 0x61, 0x73, 0x6e, 0x01, 0x00, 0x00, 0x00],
+        activation::validation_error(concat!(
+            "magic header not detected: bad magic number - expected=[\n",
+            "    0x0,\n    0x61,\n    0x73,\n    0x6d,\n] actual=[\n",
+            "    0x0,\n    0x61,\n    0x73,\n    0x6e,\n] (at offset 0x0)",
+        )),
+    );
+}
+
+#[test]
+pub fn floats_are_rejected() {
+    assert_rejected(
+        wat(include_str!("fixtures/invalid-wasm/floats.wat")),
+        activation::validation_error("floating-point instruction disallowed (at offset 0x3d)"),
+    );
+}
+
+#[test]
+pub fn too_many_tables_are_rejected() {
+    assert_rejected(
+        wat(include_str!("fixtures/invalid-wasm/too-many-tables.wat")),
+        activation::validation_error("multiple tables (at offset 0x16)"),
+    );
+}
+
+#[test]
+pub fn missing_entrypoint_is_rejected() {
+    assert_rejected(
+        wat(include_str!("fixtures/invalid-wasm/missing-entrypoint.wat")),
+        activation::parse_error(format!("missing export with name {}", "user_entrypoint".red())),
+    );
+}
+
+#[test]
+pub fn bad_entrypoint_signature_is_rejected() {
+    assert_rejected(
+        wat(include_str!("fixtures/invalid-wasm/bad-entrypoint-signature.wat")),
+        activation::parse_error(format!(
+            "wrong type for {}: {}",
+            "user_entrypoint".red(),
+            "λ(i64) -> i32".red()
+        )),
+    );
+}
+
+#[test]
+pub fn missing_memory_export_is_rejected() {
+    assert_rejected(
+        wat(include_str!("fixtures/invalid-wasm/missing-memory-export.wat")),
+        activation::parse_error(format!("missing export with name {}", "memory".red())),
+    );
+}
+
+#[test]
+pub fn memory_over_limit_is_rejected() {
+    assert_rejected(
+        wat(include_str!("fixtures/invalid-wasm/memory-exceeds-limit.wat")),
+        activation::parse_error(format!("memory exceeds limit: {} > {}", 129.red(), 128.red())),
+    );
+}
+
+#[test]
+pub fn start_function_is_rejected() {
+    assert_rejected(
+        wat(include_str!("fixtures/invalid-wasm/start-function.wat")),
+        activation::parse_error("wasm start functions not allowed"),
+    );
+}
+
+#[test]
+pub fn too_many_functions_are_rejected() {
+    // 4096 functions plus the entrypoint.
+    let source = format!(
+        r#"(module
+            (memory (export "memory") 1 1)
+            {}
+            (func (export "user_entrypoint") (param $args_len i32) (result i32)
+                i32.const 0))"#,
+        "(func)\n".repeat(4096)
+    );
+
+    assert_rejected(wat(&source), activation::parse_error("too many wasm functions: 4097 > 4096"));
+}
+
+#[test]
+pub fn too_many_globals_are_rejected() {
+    let wasm = wat(include_str!("fixtures/invalid-wasm/too-many-globals.wat"));
+
+    // Only compressed does the program fit under the code size limit. Activation measures
+    // the decompressed WASM before counting anything in it.
+    let code = compress_wasm(&wasm);
+    assert!(STYLUS_MAGIC_BYTES.len() + code.len() <= STYLUS_MAX_CODE_SIZE);
+
+    assert_rejected(code, activation::wasm_too_large(wasm.len()));
+}
+
+#[test]
+pub fn oversized_data_is_rejected() {
+    assert_rejected(
+        wat(include_str!("fixtures/invalid-wasm/oversized-data.wat")),
+        activation::build_error("Out-of-bounds data memory init with offset 65520 and size 32"),
+    );
+}
+
+#[test]
+pub fn unknown_hostio_is_rejected() {
+    assert_rejected(
+        wat(include_str!("fixtures/invalid-wasm/unknown-hostio.wat")),
+        activation::build_error(format!(
+            "No such import {} in {} for {}",
+            "not_a_hostio".red(),
+            "vm_hooks".red(),
+            "user".red()
+        )),
+    );
+}
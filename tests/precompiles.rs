@@ -0,0 +1,369 @@
+use alloy_sol_types::SolCall;
+use common::fixtures::base_state;
+use common::multicall::Multicaller;
+use common::DEPLOYER;
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{address, hex, Address, ExecutionResult, TxEnv, TxKind, U256};
+
+mod common;
+
+const ECRECOVER: Address = address!("0000000000000000000000000000000000000001");
+const SHA256: Address = address!("0000000000000000000000000000000000000002");
+const RIPEMD160: Address = address!("0000000000000000000000000000000000000003");
+const IDENTITY: Address = address!("0000000000000000000000000000000000000004");
+const MODEXP: Address = address!("0000000000000000000000000000000000000005");
+const BN254_ADD: Address = address!("0000000000000000000000000000000000000006");
+const BN254_MUL: Address = address!("0000000000000000000000000000000000000007");
+const BN254_PAIRING: Address = address!("0000000000000000000000000000000000000008");
+const BLAKE2F: Address = address!("0000000000000000000000000000000000000009");
+const POINT_EVALUATION: Address = address!("000000000000000000000000000000000000000a");
+
+/// keccak256("hello precompiles"), signed by the key keccak256("precompile signer").
+const ECRECOVER_INPUT: &str = concat!(
+    "7d64fb140eb052b866bd3e5854a5811c7f3dae592c0d6a4c95b5a234b54d1142",
+    "000000000000000000000000000000000000000000000000000000000000001b",
+    "81e2fd37eb22e74896959ddd05badfa91431eb9e8316840c617084bc3e193eac",
+    "29ecdc6aa4fa20e9dc94f94074c33f9cfeec9bb74e7f02162753fc894d9192b9",
+);
+const SIGNER: &str = "00000000000000000000000096a27e95dd7d26338e59cf7e5262bffdd239ff50";
+
+const BN254_G1: &str = concat!(
+    "0000000000000000000000000000000000000000000000000000000000000001",
+    "0000000000000000000000000000000000000000000000000000000000000002",
+);
+const BN254_NEG_G1: &str = concat!(
+    "0000000000000000000000000000000000000000000000000000000000000001",
+    "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45",
+);
+const BN254_2G1: &str = concat!(
+    "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3",
+    "15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4",
+);
+/// Not on the curve.
+const BN254_BAD_G1: &str = concat!(
+    "0000000000000000000000000000000000000000000000000000000000000001",
+    "0000000000000000000000000000000000000000000000000000000000000003",
+);
+const BN254_G2: &str = concat!(
+    "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+    "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+);
+
+/// EIP-152 test vector 5: BLAKE2b-512 of "abc", 12 rounds.
+const BLAKE2F_INPUT: &str = concat!(
+    "0000000c",
+    "48c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5",
+    "d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e1319cde05b",
+    "6162630000000000000000000000000000000000000000000000000000000000",
+    "0000000000000000000000000000000000000000000000000000000000000000",
+    "0000000000000000000000000000000000000000000000000000000000000000",
+    "0000000000000000000000000000000000000000000000000000000000000000",
+    "0300000000000000",
+    "0000000000000000",
+    "01",
+);
+const BLAKE2F_OUTPUT: &str = concat!(
+    "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1",
+    "7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923",
+);
+
+/// Evaluates the zero polynomial at zero: the commitment and proof are both the point at
+/// infinity.
+const POINT_EVALUATION_INPUT: &str = concat!(
+    "010657f37554c781402a22917dee2f75def7ab966d7b770905398eba3c444014",
+    "0000000000000000000000000000000000000000000000000000000000000000",
+    "0000000000000000000000000000000000000000000000000000000000000000",
+    "c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+    "c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+);
+/// FIELD_ELEMENTS_PER_BLOB followed by the BLS12-381 scalar field modulus.
+const POINT_EVALUATION_OUTPUT: &str = concat!(
+    "0000000000000000000000000000000000000000000000000000000000001000",
+    "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+);
+
+struct Vector {
+    name: &'static str,
+    precompile: Address,
+    input: String,
+    output: String,
+    /// Gas the precompile charges for `input`.
+    gas: u64,
+}
+
+fn vector(name: &'static str, precompile: Address, input: &str, output: &str, gas: u64) -> Vector {
+    Vector {
+        name,
+        precompile,
+        input: input.to_string(),
+        output: output.to_string(),
+        gas,
+    }
+}
+
+fn vectors() -> Vec<Vector> {
+    vec![
+        vector("ecrecover", ECRECOVER, ECRECOVER_INPUT, SIGNER, 3000),
+        vector(
+            "sha256",
+            SHA256,
+            "616263",
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            72,
+        ),
+        vector(
+            "ripemd160",
+            RIPEMD160,
+            "616263",
+            "0000000000000000000000008eb208f7e05d987a9b044a8e98c6b087f15a0bfc",
+            720,
+        ),
+        vector("identity", IDENTITY, "616263", "616263", 18),
+        // 3^5 mod 7
+        vector(
+            "modexp",
+            MODEXP,
+            concat!(
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "030507",
+            ),
+            "05",
+            200,
+        ),
+        vector(
+            "bn254 add",
+            BN254_ADD,
+            &[BN254_G1, BN254_G1].concat(),
+            BN254_2G1,
+            150,
+        ),
+        vector(
+            "bn254 mul",
+            BN254_MUL,
+            &[
+                BN254_G1,
+                "0000000000000000000000000000000000000000000000000000000000000002",
+            ]
+            .concat(),
+            BN254_2G1,
+            6000,
+        ),
+        // e(G1, G2) * e(-G1, G2) == 1
+        vector(
+            "bn254 pairing",
+            BN254_PAIRING,
+            &[BN254_G1, BN254_G2, BN254_NEG_G1, BN254_G2].concat(),
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            45000 + 2 * 34000,
+        ),
+        vector("blake2f", BLAKE2F, BLAKE2F_INPUT, BLAKE2F_OUTPUT, 12),
+        vector(
+            "point evaluation",
+            POINT_EVALUATION,
+            POINT_EVALUATION_INPUT,
+            POINT_EVALUATION_OUTPUT,
+            50000,
+        ),
+    ]
+}
+
+/// How a precompile treats malformed input.
+#[derive(Debug)]
+enum Rejection {
+    /// The call succeeds without output.
+    EmptyOutput,
+    /// The call fails, consuming the gas it was given.
+    Failure,
+}
+
+fn malformed_inputs() -> Vec<(&'static str, Address, String, Rejection)> {
+    let mut bad_v = hex::decode(ECRECOVER_INPUT).unwrap();
+    bad_v[63] = 29;
+
+    let mut bad_blake2f_flag = hex::decode(BLAKE2F_INPUT).unwrap();
+    *bad_blake2f_flag.last_mut().unwrap() = 2;
+
+    let mut bad_versioned_hash = hex::decode(POINT_EVALUATION_INPUT).unwrap();
+    bad_versioned_hash[0] = 2;
+
+    vec![
+        ("ecrecover with invalid v", ECRECOVER, hex::encode(bad_v), Rejection::EmptyOutput),
+        ("bn254 add off the curve", BN254_ADD, [BN254_G1, BN254_BAD_G1].concat(), Rejection::Failure),
+        (
+            "bn254 mul off the curve",
+            BN254_MUL,
+            [BN254_BAD_G1, "0000000000000000000000000000000000000000000000000000000000000002"].concat(),
+            Rejection::Failure,
+        ),
+        (
+            "bn254 pairing with truncated pair",
+            BN254_PAIRING,
+            [BN254_G1, &BN254_G2[..BN254_G2.len() - 2]].concat(),
+            Rejection::Failure,
+        ),
+        (
+            "blake2f with truncated input",
+            BLAKE2F,
+            BLAKE2F_INPUT[..BLAKE2F_INPUT.len() - 2].to_string(),
+            Rejection::Failure,
+        ),
+        ("blake2f with invalid final flag", BLAKE2F, hex::encode(bad_blake2f_flag), Rejection::Failure),
+        (
+            "point evaluation with wrong versioned hash",
+            POINT_EVALUATION,
+            hex::encode(bad_versioned_hash),
+            Rejection::Failure,
+        ),
+        (
+            "point evaluation with truncated input",
+            POINT_EVALUATION,
+            POINT_EVALUATION_INPUT[..POINT_EVALUATION_INPUT.len() - 2].to_string(),
+            Rejection::Failure,
+        ),
+    ]
+}
+
+struct Setup {
+    db: CacheDB<EmptyDB>,
+    multicall: Address,
+    multicall_evm: Address,
+}
+
+impl Setup {
+    fn new() -> Self {
+        let (db, fixtures) = base_state();
+
+        Self {
+            db,
+            multicall: fixtures.multicall,
+            multicall_evm: fixtures.multicall_evm,
+        }
+    }
+
+    /// Calls `precompile` through `caller` with `gas_limit` gas, zero meaning all of it.
+    fn call(&mut self, caller: Address, precompile: Address, input: &str, gas_limit: u64) -> ExecutionResult {
+        let calldata = Multicaller::multicallCall {
+            calls: vec![Multicaller::Call {
+                callType: Multicaller::CallType::CALL,
+                target: precompile,
+                data: hex::decode(input).unwrap().into(),
+                value: U256::ZERO,
+                gas_limit: U256::from(gas_limit),
+            }],
+        };
+
+        let mut evm = revm::Evm::builder()
+            .with_db(&mut self.db)
+            .modify_tx_env(|tx: &mut TxEnv| {
+                tx.caller = DEPLOYER;
+                tx.transact_to = TxKind::Call(caller);
+                tx.data = calldata.abi_encode().into();
+                tx.gas_limit = 1_000_000_000;
+            })
+            .build();
+
+        evm.transact().unwrap().result
+    }
+
+    fn callers(&self) -> [(&'static str, Address); 2] {
+        [("stylus", self.multicall), ("evm", self.multicall_evm)]
+    }
+}
+
+fn precompile_output(result: &ExecutionResult) -> Vec<u8> {
+    Multicaller::multicallCall::abi_decode_returns(result.output().unwrap(), true)
+        .unwrap()
+        .results[0]
+        .to_vec()
+}
+
+#[test]
+pub fn precompile_vectors() {
+    let mut setup = Setup::new();
+
+    for vector in vectors() {
+        for (caller_name, caller) in setup.callers() {
+            let result = setup.call(caller, vector.precompile, &vector.input, 0);
+
+            assert!(result.is_success(), "{} from {}: {:?}", vector.name, caller_name, result);
+            assert_eq!(
+                hex::encode(precompile_output(&result)),
+                vector.output,
+                "{} from {}",
+                vector.name,
+                caller_name
+            );
+        }
+    }
+}
+
+#[test]
+pub fn precompile_gas() {
+    let mut setup = Setup::new();
+
+    // Giving the call exactly the precompile's cost pins down what it charges the caller.
+    for vector in vectors() {
+        for (caller_name, caller) in setup.callers() {
+            let result = setup.call(caller, vector.precompile, &vector.input, vector.gas);
+            assert!(result.is_success(), "{} from {}: {:?}", vector.name, caller_name, result);
+            assert_eq!(hex::encode(precompile_output(&result)), vector.output);
+
+            let result = setup.call(caller, vector.precompile, &vector.input, vector.gas - 1);
+            match result {
+                ExecutionResult::Revert { output, .. } => {
+                    assert!(output.is_empty(), "{} from {}", vector.name, caller_name)
+                }
+                result => panic!("{} from {}: expected revert: {:?}", vector.name, caller_name, result),
+            }
+        }
+    }
+}
+
+#[test]
+pub fn precompile_malformed_inputs() {
+    let mut setup = Setup::new();
+
+    for (name, precompile, input, rejection) in malformed_inputs() {
+        let mut outcomes = vec![];
+
+        for (caller_name, caller) in setup.callers() {
+            let result = setup.call(caller, precompile, &input, 0);
+
+            match (&rejection, &result) {
+                (Rejection::EmptyOutput, ExecutionResult::Success { .. }) => {
+                    assert!(precompile_output(&result).is_empty(), "{} from {}", name, caller_name);
+                }
+                // Multicall bubbles the failure, which carries no data.
+                (Rejection::Failure, ExecutionResult::Revert { output, .. }) => {
+                    assert!(output.is_empty(), "{} from {}", name, caller_name);
+                }
+                _ => panic!("{} from {}: expected {:?}: {:?}", name, caller_name, rejection, result),
+            }
+
+            outcomes.push(result.is_success());
+        }
+
+        assert_eq!(outcomes[0], outcomes[1], "{}", name);
+    }
+}
+
+#[test]
+pub fn precompile_failure_consumes_call_gas() {
+    let mut setup = Setup::new();
+    // Both limits encode to a single non-zero byte, so calldata costs the same.
+    let (small, large) = (0x10000, 0x20000);
+
+    // A failing precompile burns everything it was given, so the caller pays for the
+    // whole allowance however cheap the input would have been.
+    let input = [BN254_G1, BN254_BAD_G1].concat();
+    for (caller_name, caller) in setup.callers() {
+        let small_gas_used = setup.call(caller, BN254_ADD, &input, small).gas_used();
+        let large_gas_used = setup.call(caller, BN254_ADD, &input, large).gas_used();
+
+        assert_eq!(large_gas_used - small_gas_used, large - small, "{}", caller_name);
+    }
+}
@@ -0,0 +1,270 @@
+use alloy_sol_types::{sol, SolCall};
+use common::fixtures::{base_state, Fixtures};
+use common::gas::GasBreakdown;
+use common::multicall::{self, multicall, Multicaller};
+use common::{deploy_solidity, evm_contract_init_code, DEPLOYER};
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{keccak256, Address, ExecutionResult, TxEnv, TxKind, B256, U256};
+
+mod common;
+
+sol!("tests/assets/abi/test_program.sol");
+sol!("tests/assets/abi/bench_program.sol");
+sol!("tests/assets/abi/recursive.sol");
+
+/// EIP-3529 refund for clearing a slot that was non-zero when the transaction started.
+const SSTORE_CLEARS_SCHEDULE: u64 = 4800;
+/// EIP-2200 refund for restoring a non-zero slot: the reset cost less a warm read.
+const RESTORE_REFUND: u64 = 2900 - 100;
+/// EIP-2200 refund for restoring a slot that started out zero: the set cost less a warm
+/// read.
+const RESTORE_ZERO_REFUND: u64 = 20_000 - 100;
+
+/// EVM counterpart of `setStorage(bytes32,bytes32)`: a bare `SSTORE` of the two words
+/// after the selector.
+const SET_STORAGE_RUNTIME: &[u8] = &[0x60, 0x24, 0x35, 0x60, 0x04, 0x35, 0x55, 0x00];
+/// Reverts without return data.
+const REVERT_RUNTIME: &[u8] = &[0x5f, 0x5f, 0xfd];
+
+/// Hashing rounds that burn enough gas for the refunds under test to stay below the cap.
+const PADDING_ROUNDS: u64 = 2000;
+
+const GAS_LIMIT: u64 = 10_000_000;
+
+#[derive(Clone, Copy, Debug)]
+enum Vm {
+    Stylus,
+    Evm,
+}
+
+const VMS: [Vm; 2] = [Vm::Stylus, Vm::Evm];
+
+fn slot(index: usize) -> B256 {
+    keccak256(format!("refund-slot-{}", index))
+}
+
+fn original() -> B256 {
+    keccak256("refund-original")
+}
+
+fn other() -> B256 {
+    keccak256("refund-other")
+}
+
+fn call(target: Address, data: Vec<u8>) -> Multicaller::Call {
+    multicall::call(Multicaller::CallType::CALL, target, data)
+}
+
+struct Setup {
+    db: CacheDB<EmptyDB>,
+    fixtures: Fixtures,
+    evm_storage: Address,
+    reverter: Address,
+}
+
+impl Setup {
+    fn new() -> Self {
+        let (mut db, fixtures) = base_state();
+        let mut deploy = |runtime: &[u8]| {
+            deploy_solidity(&mut db, evm_contract_init_code(runtime.to_vec()), DEPLOYER)
+        };
+        let evm_storage = deploy(SET_STORAGE_RUNTIME);
+        let reverter = deploy(REVERT_RUNTIME);
+
+        Self {
+            db,
+            fixtures,
+            evm_storage,
+            reverter,
+        }
+    }
+
+    /// Where `setStorage` writes with the VM's own storage writes: the Stylus test program
+    /// or the bare `SSTORE` contract.
+    fn target(&self, vm: Vm) -> Address {
+        match vm {
+            Vm::Stylus => self.fixtures.test_program,
+            Vm::Evm => self.evm_storage,
+        }
+    }
+
+    /// Sets the slot's value from before the transaction.
+    fn set_original(&mut self, vm: Vm, key: B256, value: B256) {
+        let target = self.target(vm);
+        let account = self.db.load_account(target).unwrap();
+        account.storage.insert(key.into(), value.into());
+    }
+
+    fn storage(&mut self, vm: Vm, key: B256) -> U256 {
+        let target = self.target(vm);
+        let account = self.db.load_account(target).unwrap();
+        account.storage.get(&key.into()).copied().unwrap_or_default()
+    }
+
+    /// One call to the target per write, each in its own frame so Stylus flushes them one
+    /// at a time.
+    fn writes(&self, vm: Vm, writes: &[(B256, B256)]) -> Vec<Multicaller::Call> {
+        let target = self.target(vm);
+        writes
+            .iter()
+            .map(|&(key, value)| call(target, ITestProgram::setStorageCall { key, value }.abi_encode()))
+            .collect()
+    }
+
+    /// Calls that clear every slot in `keys`: all in one call to the Stylus program, which
+    /// would otherwise pay for a program call per slot, or one `SSTORE` call per slot.
+    fn clears(&self, vm: Vm, keys: Vec<B256>) -> Vec<Multicaller::Call> {
+        match vm {
+            Vm::Stylus => {
+                let data = ITestProgram::clearStorageCall { keys };
+                vec![call(self.fixtures.test_program, data.abi_encode())]
+            }
+            Vm::Evm => {
+                let writes: Vec<_> = keys.into_iter().map(|key| (key, B256::ZERO)).collect();
+                self.writes(vm, &writes)
+            }
+        }
+    }
+
+    /// A call that only burns gas.
+    fn padding(&self) -> Multicaller::Call {
+        let data = IBenchProgram::keccakLoopCall {
+            seed: B256::ZERO,
+            count: PADDING_ROUNDS,
+        };
+        call(self.fixtures.bench_program_evm, data.abi_encode())
+    }
+
+    /// Sends `calls` through the EVM multicall, returning the result and its gas breakdown.
+    fn transact(&mut self, calls: Vec<Multicaller::Call>) -> (ExecutionResult, GasBreakdown) {
+        let to = self.fixtures.multicall_evm;
+        let data = multicall(calls);
+        let tx_data = data.clone();
+
+        let evm = revm::Evm::builder()
+            .with_db(&mut self.db)
+            .modify_tx_env(|tx: &mut TxEnv| {
+                tx.caller = DEPLOYER;
+                tx.transact_to = TxKind::Call(to);
+                tx.data = tx_data.into();
+                tx.gas_limit = GAS_LIMIT;
+            });
+
+        let result = evm.build().transact_commit().unwrap();
+        let gas = GasBreakdown::of_call(GAS_LIMIT, &data, &result);
+        (result, gas)
+    }
+
+    /// The refund from `writes` to a slot that held `start` before the transaction.
+    fn refund(&mut self, vm: Vm, start: B256, writes: &[B256]) -> u64 {
+        let key = slot(0);
+        self.set_original(vm, key, start);
+
+        let writes: Vec<_> = writes.iter().map(|&value| (key, value)).collect();
+        let mut calls = self.writes(vm, &writes);
+        calls.push(self.padding());
+
+        let (result, gas) = self.transact(calls);
+        assert!(result.is_success(), "{:?}: {:?}", vm, result);
+        assert!(gas.refunded < gas.refund_cap(), "{:?}: {:?}", vm, gas);
+        gas.refunded
+    }
+}
+
+#[test]
+pub fn clearing_a_slot_refunds() {
+    for vm in VMS {
+        let mut setup = Setup::new();
+        assert_eq!(setup.refund(vm, original(), &[B256::ZERO]), SSTORE_CLEARS_SCHEDULE, "{:?}", vm);
+        assert_eq!(setup.storage(vm, slot(0)), U256::ZERO);
+    }
+}
+
+#[test]
+pub fn restoring_a_slot_refunds() {
+    for vm in VMS {
+        let mut setup = Setup::new();
+        assert_eq!(setup.refund(vm, original(), &[other(), original()]), RESTORE_REFUND, "{:?}", vm);
+
+        // Clearing and then restoring takes back the clearing refund.
+        let mut setup = Setup::new();
+        assert_eq!(setup.refund(vm, original(), &[B256::ZERO, original()]), RESTORE_REFUND, "{:?}", vm);
+
+        let mut setup = Setup::new();
+        assert_eq!(setup.refund(vm, B256::ZERO, &[other(), B256::ZERO]), RESTORE_ZERO_REFUND, "{:?}", vm);
+    }
+}
+
+#[test]
+pub fn stylus_refunds_match_evm() {
+    let cases: [(B256, &[B256]); 5] = [
+        (original(), &[B256::ZERO]),
+        (original(), &[other(), original()]),
+        (original(), &[B256::ZERO, original()]),
+        (B256::ZERO, &[other(), B256::ZERO]),
+        (original(), &[other(), B256::ZERO]),
+    ];
+
+    for (start, writes) in cases {
+        let stylus = Setup::new().refund(Vm::Stylus, start, writes);
+        let evm = Setup::new().refund(Vm::Evm, start, writes);
+        assert_eq!(stylus, evm, "{:?} -> {:?}", start, writes);
+    }
+}
+
+#[test]
+pub fn refunds_are_capped_at_a_fifth_of_gas_used() {
+    const SLOTS: usize = 20;
+
+    for vm in VMS {
+        let mut setup = Setup::new();
+        for i in 0..SLOTS {
+            setup.set_original(vm, slot(i), original());
+        }
+
+        let calls = setup.clears(vm, (0..SLOTS).map(slot).collect());
+        let (result, gas) = setup.transact(calls);
+        assert!(result.is_success(), "{:?}: {:?}", vm, result);
+
+        assert!(gas.refund_cap() < SLOTS as u64 * SSTORE_CLEARS_SCHEDULE, "{:?}: {:?}", vm, gas);
+        assert_eq!(gas.refunded, gas.refund_cap(), "{:?}: {:?}", vm, gas);
+        for i in 0..SLOTS {
+            assert_eq!(setup.storage(vm, slot(i)), U256::ZERO);
+        }
+    }
+}
+
+#[test]
+pub fn reverted_frames_discard_refunds() {
+    for vm in VMS {
+        for revert in [false, true] {
+            let mut setup = Setup::new();
+            setup.set_original(vm, slot(0), original());
+
+            // The clear runs inside a multicall that tryCall shields the transaction from.
+            let mut inner = setup.writes(vm, &[(slot(0), B256::ZERO)]);
+            if revert {
+                inner.push(call(setup.reverter, vec![]));
+            }
+            let try_call = IRecursive::tryCallCall {
+                target: setup.fixtures.multicall_evm,
+                data: multicall(inner).into(),
+            };
+            let calls = vec![
+                call(setup.fixtures.recursive_evm, try_call.abi_encode()),
+                setup.padding(),
+            ];
+
+            let (result, gas) = setup.transact(calls);
+            assert!(result.is_success(), "{:?}: {:?}", vm, result);
+
+            if revert {
+                assert_eq!(gas.refunded, 0, "{:?}", vm);
+                assert_eq!(setup.storage(vm, slot(0)), U256::from_be_bytes(original().0));
+            } else {
+                assert_eq!(gas.refunded, SSTORE_CLEARS_SCHEDULE, "{:?}", vm);
+                assert_eq!(setup.storage(vm, slot(0)), U256::ZERO);
+            }
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use alloy_sol_types::{sol, SolCall};
+use common::replay::{ExpectedReceipt, ReplayBlock, ReplayBundle, ReplayLog, ReplayTx};
+use common::fixtures::base_state;
+use common::state_dump::export;
+use common::DEPLOYER;
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{
+    keccak256, Address, BlockEnv, Bytes, ExecutionResult, TxEnv, TxKind, B256, U256,
+};
+
+mod common;
+
+/// EVM-only bundle with hand-computed receipts: an SSTORE, a LOG0 whose receipt carries L1
+/// gas, and a REVERT.
+///
+/// This is a format check only. The receipts were written by hand, not recorded from a Nitro
+/// node, so matching them shows the bundle loads and compares as intended and says nothing
+/// about agreeing with Nitro. No recorded bundle is available offline.
+const FORMAT_CHECK_BUNDLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/replay/format-check.json");
+
+sol!("tests/assets/abi/test_program.sol");
+sol!("tests/assets/abi/emit_log.sol");
+
+#[test]
+pub fn format_check_bundle_matches() {
+    ReplayBundle::load(FORMAT_CHECK_BUNDLE).assert_matches();
+}
+
+#[test]
+pub fn replay_reports_mismatches() {
+    let mut bundle = ReplayBundle::load(FORMAT_CHECK_BUNDLE);
+    bundle.receipts[0].gas_used += 1;
+    bundle.receipts[1].logs.clear();
+    bundle.receipts[2].status = 1;
+
+    let mismatches = bundle.mismatches();
+
+    assert_eq!(mismatches.len(), 3, "{:#?}", mismatches);
+    assert!(mismatches[0].starts_with("tx 0: gas used"));
+    assert!(mismatches[1].starts_with("tx 1: logs"));
+    assert!(mismatches[2].starts_with("tx 2: status"));
+}
+
+#[test]
+pub fn replay_reports_l1_gas_over_receipt_gas() {
+    let mut bundle = ReplayBundle::load(FORMAT_CHECK_BUNDLE);
+    bundle.receipts[0].gas_used_for_l1 = bundle.receipts[0].gas_used + 1;
+
+    let mismatches = bundle.mismatches();
+
+    assert_eq!(
+        mismatches,
+        vec![format!(
+            "tx 0: gas used for L1 expected at most {}, got {}",
+            bundle.receipts[0].gas_used,
+            bundle.receipts[0].gas_used + 1
+        )]
+    );
+}
+
+#[test]
+pub fn replay_reports_rejected_txs() {
+    let mut bundle = ReplayBundle::load(FORMAT_CHECK_BUNDLE);
+    bundle.transactions[1].tx_type = 0x68;
+
+    let mismatches = bundle.mismatches();
+
+    // The rejected tx leaves the sender's nonce behind, so the next one is rejected too.
+    assert_eq!(mismatches.len(), 2, "{:#?}", mismatches);
+    assert_eq!(
+        mismatches[0],
+        "tx 1: result expected a receipt, got rejected: Arbitrum tx type 0x68 can't be replayed"
+    );
+    assert!(
+        mismatches[1].starts_with("tx 2: result expected a receipt, got rejected: ")
+            && mismatches[1].contains("NonceTooHigh"),
+        "{}",
+        mismatches[1]
+    );
+}
+
+fn call(from: Address, to: Address, input: Vec<u8>, nonce: u64) -> ReplayTx {
+    ReplayTx {
+        tx_type: 0,
+        from,
+        to: Some(to),
+        input: input.into(),
+        value: U256::ZERO,
+        gas: 1_000_000_000,
+        gas_price: U256::ZERO,
+        nonce,
+    }
+}
+
+/// Runs `tx` straight on the harness state, outside of the replay code.
+fn transact(db: &mut CacheDB<EmptyDB>, block: &ReplayBlock, tx: &ReplayTx) -> ExecutionResult {
+    let evm = revm::Evm::builder()
+        .with_db(db)
+        .modify_block_env(|env: &mut BlockEnv| {
+            env.number = U256::from(block.number);
+            env.timestamp = U256::from(block.timestamp);
+        })
+        .modify_tx_env(|env: &mut TxEnv| {
+            env.caller = tx.from;
+            env.transact_to = TxKind::Call(tx.to.unwrap());
+            env.data = tx.input.clone();
+            env.gas_limit = tx.gas;
+            env.nonce = Some(tx.nonce);
+        });
+
+    evm.build().transact_commit().unwrap()
+}
+
+#[test]
+pub fn stylus_bundle_matches_direct_execution() {
+    let (mut db, fixtures) = base_state();
+
+    let program = fixtures.test_program;
+    let emitter = fixtures.emit_log;
+    let nonce = db.load_account(DEPLOYER).unwrap().info.nonce;
+    let prestate = export(&db);
+
+    let block = ReplayBlock {
+        number: 1,
+        timestamp: 1,
+        coinbase: Address::ZERO,
+        base_fee_per_gas: U256::ZERO,
+        gas_limit: U256::from(u64::MAX),
+    };
+    let transactions = vec![
+        call(
+            DEPLOYER,
+            program,
+            ITestProgram::setStorageCall {
+                key: keccak256("replay-slot"),
+                value: keccak256("replay-value"),
+            }
+            .abi_encode(),
+            nonce,
+        ),
+        call(
+            DEPLOYER,
+            emitter,
+            IEmitLog::emitLogCall {
+                topics: vec![B256::repeat_byte(0x11)],
+                data: Bytes::from_static(b"replayed"),
+            }
+            .abi_encode(),
+            nonce + 1,
+        ),
+        call(
+            DEPLOYER,
+            program,
+            ITestProgram::getStorageCall { key: keccak256("replay-slot") }.abi_encode(),
+            nonce + 2,
+        ),
+    ];
+
+    // Gas comes from running the transactions on the harness state directly; logs and
+    // output are what the programs are written to produce.
+    let gas_used: Vec<u64> = transactions
+        .iter()
+        .map(|tx| transact(&mut db, &block, tx).gas_used())
+        .collect();
+    let receipt = |index: usize, logs: Vec<ReplayLog>, output: Option<Bytes>| ExpectedReceipt {
+        status: 1,
+        gas_used: gas_used[index],
+        gas_used_for_l1: 0,
+        logs,
+        output,
+    };
+    let receipts = vec![
+        receipt(0, vec![], None),
+        receipt(
+            1,
+            vec![ReplayLog {
+                address: emitter,
+                topics: vec![B256::repeat_byte(0x11)],
+                data: Bytes::from_static(b"replayed"),
+            }],
+            None,
+        ),
+        receipt(2, vec![], Some(keccak256("replay-value").into())),
+    ];
+
+    let bundle = ReplayBundle { chain_id: 412346, prestate, block, transactions, receipts };
+
+    // The bundle survives a trip through JSON and replays to the expected receipts.
+    let json = serde_json::to_string(&bundle).unwrap();
+    let parsed: ReplayBundle = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.chain_id, 412346);
+    parsed.assert_matches();
+}
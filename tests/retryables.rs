@@ -0,0 +1,338 @@
+use alloy_sol_types::{sol, SolCall};
+use common::arbitrum::{
+    execute_retry, retryable_events, ArbRetryableTx, ArbRetryableTx::ArbRetryableTxEvents,
+    ArbitrumTx, RetryableTicket, ARB_RETRYABLE_TX, RETRYABLE_LIFETIME_SECONDS,
+};
+use common::fixtures::base_state;
+use common::multicall::Multicaller;
+use common::DEPLOYER;
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{address, keccak256, Address, ExecutionResult, TxEnv, TxKind, U256};
+
+mod common;
+
+const L1_SENDER: Address = address!("5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a");
+
+sol!("tests/assets/abi/test_program.sol");
+
+struct RetryableSetup {
+    db: CacheDB<EmptyDB>,
+    multicall: Address,
+    multicall_evm: Address,
+    storage: Address,
+}
+
+impl RetryableSetup {
+    fn new() -> Self {
+        let (db, fixtures) = base_state();
+
+        Self {
+            db,
+            multicall: fixtures.multicall,
+            multicall_evm: fixtures.multicall_evm,
+            storage: fixtures.test_program,
+        }
+    }
+
+    /// Submits a ticket calling the test program, without scheduling an auto-redeem.
+    fn submit(&mut self, data: Vec<u8>, call_value: U256) -> RetryableTicket {
+        let receipt = ArbitrumTx::submit_retryable(L1_SENDER, self.storage, data)
+            .value(call_value)
+            .deposit_value(call_value)
+            .beneficiary(DEPLOYER)
+            .execute(&mut self.db, U256::ZERO);
+
+        assert!(receipt.redeem.is_none());
+        receipt.ticket.unwrap()
+    }
+
+    fn execute_commit(&mut self, to: Address, data: Vec<u8>) -> ExecutionResult {
+        let evm = revm::Evm::builder()
+            .with_db(&mut self.db)
+            .modify_tx_env(|tx: &mut TxEnv| {
+                tx.caller = DEPLOYER;
+                tx.transact_to = TxKind::Call(to);
+                tx.data = data.into();
+                tx.gas_limit = 1_000_000_000;
+            });
+
+        evm.build().transact_commit().unwrap()
+    }
+
+    fn timeout(&mut self, ticket: &RetryableTicket) -> Option<U256> {
+        let calldata = ArbRetryableTx::getTimeoutCall { ticketId: ticket.id };
+        let result = self.execute_commit(ARB_RETRYABLE_TX, calldata.abi_encode());
+
+        result.is_success().then(|| {
+            ArbRetryableTx::getTimeoutCall::abi_decode_returns(result.output().unwrap(), true)
+                .unwrap()
+                ._0
+        })
+    }
+
+    fn escrowed(&mut self, ticket: &RetryableTicket) -> U256 {
+        self.db
+            .load_account(ticket.escrow_address())
+            .unwrap()
+            .info
+            .balance
+    }
+
+    /// Calls `ArbRetryableTx.redeem` through `multicaller` and returns the retry it scheduled.
+    fn redeem_through(
+        &mut self,
+        multicaller: Address,
+        ticket: &RetryableTicket,
+    ) -> ArbRetryableTx::RedeemScheduled {
+        let calldata = Multicaller::multicallCall {
+            calls: vec![Multicaller::Call {
+                callType: Multicaller::CallType::CALL,
+                target: ARB_RETRYABLE_TX,
+                data: ArbRetryableTx::redeemCall { ticketId: ticket.id }
+                    .abi_encode()
+                    .into(),
+                value: U256::ZERO,
+                gas_limit: U256::ZERO,
+            }],
+        };
+
+        let result = self.execute_commit(multicaller, calldata.abi_encode());
+        assert!(result.is_success(), "{:?}", result);
+
+        let scheduled = retryable_events(result.logs())
+            .into_iter()
+            .find_map(|event| match event {
+                ArbRetryableTxEvents::RedeemScheduled(event) => Some(event),
+                _ => None,
+            })
+            .expect("redeem did not schedule a retry");
+
+        assert_eq!(scheduled.ticketId, ticket.id);
+        assert_eq!(scheduled.gasDonor, multicaller);
+        assert!(scheduled.donatedGas > 0);
+        assert_eq!(scheduled.maxRefund, U256::MAX);
+        assert_eq!(scheduled.submissionFeeRefund, U256::ZERO);
+        assert_eq!(
+            scheduled.retryTxHash,
+            ticket.retry_tx_hash(scheduled.sequenceNum, scheduled.donatedGas, multicaller)
+        );
+
+        scheduled
+    }
+}
+
+fn set_storage_call() -> Vec<u8> {
+    ITestProgram::setStorageCall {
+        key: keccak256("some-storage-slot"),
+        value: keccak256("retryable-storage-value"),
+    }
+    .abi_encode()
+}
+
+#[test]
+pub fn submission_creates_ticket_for_stylus_target() {
+    let mut setup = RetryableSetup::new();
+    let call_value = U256::from(1e17);
+
+    let receipt = ArbitrumTx::submit_retryable(L1_SENDER, setup.storage, set_storage_call())
+        .value(call_value)
+        .deposit_value(call_value)
+        .beneficiary(DEPLOYER)
+        .execute(&mut setup.db, U256::ZERO);
+    let ticket = receipt.ticket.unwrap();
+
+    // The harness writes the ticket, its logs and the escrow itself, so only what
+    // ArbRetryableTx reads back from ArbOS state is checked.
+    assert_eq!(setup.timeout(&ticket), Some(U256::from(ticket.timeout)));
+
+    let result = setup.execute_commit(
+        ARB_RETRYABLE_TX,
+        ArbRetryableTx::getBeneficiaryCall { ticketId: ticket.id }.abi_encode(),
+    );
+    assert!(result.is_success());
+    assert_eq!(
+        ArbRetryableTx::getBeneficiaryCall::abi_decode_returns(result.output().unwrap(), true)
+            .unwrap()
+            ._0,
+        DEPLOYER
+    );
+}
+
+#[test]
+pub fn lifetime_matches_nitro() {
+    let mut setup = RetryableSetup::new();
+
+    let result = setup.execute_commit(
+        ARB_RETRYABLE_TX,
+        ArbRetryableTx::getLifetimeCall {}.abi_encode(),
+    );
+
+    assert!(result.is_success());
+    assert_eq!(
+        result.output().unwrap().to_vec(),
+        U256::from(RETRYABLE_LIFETIME_SECONDS).to_be_bytes_vec()
+    );
+}
+
+fn redeem_test(through_stylus: bool) {
+    let mut setup = RetryableSetup::new();
+    let ticket = setup.submit(set_storage_call(), U256::ZERO);
+
+    let multicaller = if through_stylus {
+        setup.multicall
+    } else {
+        setup.multicall_evm
+    };
+    let scheduled = setup.redeem_through(multicaller, &ticket);
+
+    let result = execute_retry(&mut setup.db, &ticket, scheduled.donatedGas);
+    assert!(result.is_success(), "{:?}", result);
+
+    let stored_value = setup
+        .db
+        .load_account(setup.storage)
+        .unwrap()
+        .storage
+        .get(&keccak256("some-storage-slot").into())
+        .copied()
+        .unwrap();
+    assert_eq!(
+        stored_value.to_be_bytes_vec(),
+        keccak256("retryable-storage-value").to_vec()
+    );
+
+    // Redeemed tickets are deleted.
+    assert_eq!(setup.timeout(&ticket), None);
+}
+
+#[test]
+pub fn redeem_from_stylus() {
+    redeem_test(true);
+}
+
+#[test]
+pub fn redeem_from_evm() {
+    redeem_test(false);
+}
+
+#[test]
+pub fn failed_redeem_leaves_ticket_redeemable() {
+    let mut setup = RetryableSetup::new();
+    let call_value = U256::from(1e17);
+
+    // The test program does not implement this selector, so every retry reverts.
+    let ticket = setup.submit(vec![0xde, 0xad, 0xbe, 0xef], call_value);
+
+    // Without an auto-redeem, the first manual redeem is the ticket's first try.
+    let scheduled = setup.redeem_through(setup.multicall, &ticket);
+    assert_eq!(scheduled.sequenceNum, 0);
+    let result = execute_retry(&mut setup.db, &ticket, scheduled.donatedGas);
+
+    assert!(!result.is_success());
+    assert_eq!(setup.timeout(&ticket), Some(U256::from(ticket.timeout)));
+    assert_eq!(
+        setup.db.load_account(setup.storage).unwrap().info.balance,
+        U256::ZERO
+    );
+
+    // The ticket can be redeemed again. `redeem` counted the first try, not the retry.
+    let scheduled = setup.redeem_through(setup.multicall_evm, &ticket);
+    assert_eq!(scheduled.sequenceNum, 1);
+}
+
+#[test]
+pub fn failed_auto_redeem_counts_as_first_try() {
+    let mut setup = RetryableSetup::new();
+
+    let data = vec![0xde, 0xad, 0xbe, 0xef];
+    let receipt = ArbitrumTx::submit_retryable(L1_SENDER, setup.storage, data)
+        .deposit_value(U256::from(1e18))
+        .gas_limit(1_000_000)
+        .gas_fee_cap(U256::from(1))
+        .beneficiary(DEPLOYER)
+        .execute(&mut setup.db, U256::ZERO);
+    assert!(!receipt.redeem.unwrap().is_success());
+    let ticket = receipt.ticket.unwrap();
+
+    // The auto-redeem was sequence number 0, so the first manual redeem is the second try.
+    let scheduled = setup.redeem_through(setup.multicall, &ticket);
+    assert_eq!(scheduled.sequenceNum, 1);
+}
+
+#[test]
+pub fn keepalive_extends_timeout() {
+    let mut setup = RetryableSetup::new();
+    let ticket = setup.submit(set_storage_call(), U256::ZERO);
+    let new_timeout = U256::from(ticket.timeout + RETRYABLE_LIFETIME_SECONDS);
+
+    let result = setup.execute_commit(
+        ARB_RETRYABLE_TX,
+        ArbRetryableTx::keepaliveCall { ticketId: ticket.id }.abi_encode(),
+    );
+    assert!(result.is_success(), "{:?}", result);
+    assert_eq!(result.output().unwrap().to_vec(), new_timeout.to_be_bytes_vec());
+
+    let events = retryable_events(result.logs());
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        ArbRetryableTxEvents::LifetimeExtended(event) => {
+            assert_eq!(event.ticketId, ticket.id);
+            assert_eq!(event.newTimeout, new_timeout);
+        }
+        event => panic!("Expected LifetimeExtended: {:?}", event),
+    }
+
+    assert_eq!(setup.timeout(&ticket), Some(new_timeout));
+}
+
+#[test]
+pub fn cancel_refunds_beneficiary() {
+    let mut setup = RetryableSetup::new();
+    let call_value = U256::from(1e17);
+    let ticket = setup.submit(set_storage_call(), call_value);
+
+    let balance = setup.db.load_account(DEPLOYER).unwrap().info.balance;
+
+    let result = setup.execute_commit(
+        ARB_RETRYABLE_TX,
+        ArbRetryableTx::cancelCall { ticketId: ticket.id }.abi_encode(),
+    );
+    assert!(result.is_success(), "{:?}", result);
+
+    let events = retryable_events(result.logs());
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        ArbRetryableTxEvents::Canceled(event) => assert_eq!(event.ticketId, ticket.id),
+        event => panic!("Expected Canceled: {:?}", event),
+    }
+
+    assert_eq!(setup.timeout(&ticket), None);
+    assert_eq!(setup.escrowed(&ticket), U256::ZERO);
+    assert_eq!(
+        setup.db.load_account(DEPLOYER).unwrap().info.balance,
+        balance + call_value
+    );
+}
+
+#[test]
+pub fn cancel_requires_beneficiary() {
+    let mut setup = RetryableSetup::new();
+    let ticket = setup.submit(set_storage_call(), U256::ZERO);
+
+    // The multicaller is not the ticket's beneficiary.
+    let calldata = Multicaller::multicallCall {
+        calls: vec![Multicaller::Call {
+            callType: Multicaller::CallType::CALL,
+            target: ARB_RETRYABLE_TX,
+            data: ArbRetryableTx::cancelCall { ticketId: ticket.id }
+                .abi_encode()
+                .into(),
+            value: U256::ZERO,
+            gas_limit: U256::ZERO,
+        }],
+    };
+    let result = setup.execute_commit(setup.multicall, calldata.abi_encode());
+
+    assert!(!result.is_success());
+    assert_eq!(setup.timeout(&ticket), Some(U256::from(ticket.timeout)));
+}
@@ -0,0 +1,303 @@
+use alloy_sol_types::{sol, SolCall};
+use common::fixtures::{base_state, Fixtures};
+use common::{deploy_solidity, evm_contract_init_code, reverting_init_code, DEPLOYER};
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{address, Address, Bytes, ExecutionResult, TxEnv, TxKind, B256};
+
+mod common;
+
+sol!("tests/assets/abi/hostio_probe.sol");
+sol!("tests/assets/abi/test_program.sol");
+
+/// An account with no code.
+const EOA: Address = address!("4242424242424242424242424242424242424242");
+
+/// Returns its calldata: `CALLDATACOPY` it to memory, then `RETURN` it.
+const ECHO_RUNTIME: &[u8] = &[0x36, 0x5f, 0x5f, 0x37, 0x36, 0x5f, 0xf3];
+/// Reverts with its calldata.
+const REVERT_ECHO_RUNTIME: &[u8] = &[0x36, 0x5f, 0x5f, 0x37, 0x36, 0x5f, 0xfd];
+/// Jumps back to its start until it runs out of gas.
+const SPIN_RUNTIME: &[u8] = &[0x5b, 0x5f, 0x56];
+
+/// Gas given to the call that runs out of it.
+const SPIN_GAS: u64 = 10_000;
+
+/// What the failing init code reverts with.
+fn revert_word() -> B256 {
+    B256::left_padding_from(&[0xde, 0xad, 0xbe, 0xef])
+}
+
+fn payload() -> Bytes {
+    (0..100u8).collect::<Vec<u8>>().into()
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Vm {
+    Stylus,
+    Evm,
+}
+
+/// What the probe does before reading the return data.
+#[derive(Clone, Copy, Debug)]
+enum Case {
+    /// Calls an EVM contract that returns the payload.
+    Returned,
+    /// Calls a Stylus program that returns the ABI-encoded payload.
+    StylusReturned,
+    /// Calls an EVM contract that reverts with the payload.
+    Reverted,
+    /// Calls an EVM contract that runs out of gas.
+    OutOfGas,
+    /// Calls an account without code.
+    Eoa,
+    /// Deploys a contract.
+    Created,
+    /// Deploys init code that reverts.
+    FailedCreate,
+}
+
+const CASES: [Case; 7] = [
+    Case::Returned,
+    Case::StylusReturned,
+    Case::Reverted,
+    Case::OutOfGas,
+    Case::Eoa,
+    Case::Created,
+    Case::FailedCreate,
+];
+
+/// What the probe reported: whether the call or create succeeded, the return data size,
+/// and the bytes it copied.
+#[derive(Debug, PartialEq, Eq)]
+struct Observed {
+    success: bool,
+    length: u64,
+    copied: Bytes,
+}
+
+impl Case {
+    /// Whether the call or create succeeds, and the return data it leaves behind.
+    fn expected(self) -> (bool, Vec<u8>) {
+        match self {
+            Case::Returned => (true, payload().to_vec()),
+            Case::StylusReturned => (
+                true,
+                ITestProgram::returnDataCall::abi_encode_returns(&(payload(),)),
+            ),
+            Case::Reverted => (false, payload().to_vec()),
+            Case::OutOfGas => (false, vec![]),
+            Case::Eoa => (true, vec![]),
+            Case::Created => (true, vec![]),
+            Case::FailedCreate => (false, revert_word().to_vec()),
+        }
+    }
+}
+
+/// Reads within the return data, which both VMs serve the same way.
+fn in_range_reads(length: u64) -> Vec<(u64, u64)> {
+    let mut reads = vec![(0, 0), (0, length), (length, 0)];
+    if length >= 16 {
+        reads.extend([(1, length - 1), (3, 10), (length / 2, length / 4), (length - 4, 4)]);
+    }
+    reads
+}
+
+/// Reads running past the end of the return data: `RETURNDATACOPY` halts on these, while
+/// `read_return_data` copies whatever part of the range exists.
+fn out_of_range_reads(length: u64) -> Vec<(u64, u64)> {
+    let mut reads = vec![(0, length + 1), (length + 1, 0), (length + 32, 32)];
+    if length >= 4 {
+        reads.push((length - 4, 8));
+    }
+    reads
+}
+
+struct Setup {
+    db: CacheDB<EmptyDB>,
+    fixtures: Fixtures,
+    echo: Address,
+    revert_echo: Address,
+    spin: Address,
+}
+
+impl Setup {
+    fn new() -> Self {
+        let (mut db, fixtures) = base_state();
+        let mut deploy = |runtime: &[u8]| {
+            deploy_solidity(&mut db, evm_contract_init_code(runtime.to_vec()), DEPLOYER)
+        };
+        let echo = deploy(ECHO_RUNTIME);
+        let revert_echo = deploy(REVERT_ECHO_RUNTIME);
+        let spin = deploy(SPIN_RUNTIME);
+
+        Self {
+            db,
+            fixtures,
+            echo,
+            revert_echo,
+            spin,
+        }
+    }
+
+    fn probe(&self, vm: Vm) -> Address {
+        match vm {
+            Vm::Stylus => self.fixtures.hostio_probe,
+            Vm::Evm => self.fixtures.hostio_probe_evm,
+        }
+    }
+
+    fn calldata(&self, case: Case, offset: u64, size: u64) -> Vec<u8> {
+        let call = |target: Address, data: Bytes, gas: u64| {
+            IHostioProbe::returnDataAfterCallCall {
+                target,
+                data,
+                gas,
+                offset,
+                size,
+            }
+            .abi_encode()
+        };
+        let create = |init_code: Vec<u8>| {
+            IHostioProbe::returnDataAfterCreateCall {
+                init_code: init_code.into(),
+                offset,
+                size,
+            }
+            .abi_encode()
+        };
+
+        match case {
+            Case::Returned => call(self.echo, payload(), 0),
+            Case::StylusReturned => call(
+                self.fixtures.test_program,
+                ITestProgram::returnDataCall { data: payload() }.abi_encode().into(),
+                0,
+            ),
+            Case::Reverted => call(self.revert_echo, payload(), 0),
+            Case::OutOfGas => call(self.spin, Bytes::new(), SPIN_GAS),
+            Case::Eoa => call(EOA, payload(), 0),
+            Case::Created => create(evm_contract_init_code(ECHO_RUNTIME.to_vec())),
+            Case::FailedCreate => create(reverting_init_code(revert_word())),
+        }
+    }
+
+    /// Runs the case in the probe, then has it read `size` bytes from `offset`. `None` if
+    /// the probe itself failed.
+    fn observe(&mut self, vm: Vm, case: Case, offset: u64, size: u64) -> Option<Observed> {
+        let probe = self.probe(vm);
+        let data = self.calldata(case, offset, size);
+
+        let mut evm = revm::Evm::builder()
+            .with_db(&mut self.db)
+            .modify_tx_env(|tx: &mut TxEnv| {
+                tx.caller = DEPLOYER;
+                tx.transact_to = TxKind::Call(probe);
+                tx.data = data.into();
+                tx.gas_limit = 10_000_000;
+            })
+            .build();
+
+        let result = evm.transact().unwrap().result;
+        let ExecutionResult::Success { .. } = result else {
+            return None;
+        };
+        let output = result.output().unwrap();
+
+        let observed = match case {
+            Case::Created | Case::FailedCreate => {
+                let returns =
+                    IHostioProbe::returnDataAfterCreateCall::abi_decode_returns(output, true).unwrap();
+                Observed {
+                    success: returns._0 != Address::ZERO,
+                    length: returns._1,
+                    copied: returns._2,
+                }
+            }
+            _ => {
+                let returns =
+                    IHostioProbe::returnDataAfterCallCall::abi_decode_returns(output, true).unwrap();
+                Observed {
+                    success: returns._0,
+                    length: returns._1,
+                    copied: returns._2,
+                }
+            }
+        };
+        Some(observed)
+    }
+}
+
+#[test]
+pub fn return_data_matches_evm_within_range() {
+    let mut setup = Setup::new();
+
+    for case in CASES {
+        let (success, data) = case.expected();
+        let length = data.len() as u64;
+
+        for (offset, size) in in_range_reads(length) {
+            let expected = Observed {
+                success,
+                length,
+                copied: data[offset as usize..(offset + size) as usize].to_vec().into(),
+            };
+
+            for vm in [Vm::Stylus, Vm::Evm] {
+                let observed = setup.observe(vm, case, offset, size);
+                assert_eq!(
+                    observed.as_ref(),
+                    Some(&expected),
+                    "{:?} {:?} reading {} bytes from {}",
+                    vm,
+                    case,
+                    size,
+                    offset
+                );
+            }
+        }
+    }
+}
+
+#[test]
+pub fn out_of_range_reads_halt_evm() {
+    let mut setup = Setup::new();
+
+    for case in CASES {
+        let length = case.expected().1.len() as u64;
+
+        for (offset, size) in out_of_range_reads(length) {
+            let observed = setup.observe(Vm::Evm, case, offset, size);
+            assert_eq!(observed, None, "{:?} reading {} bytes from {}", case, size, offset);
+        }
+    }
+}
+
+#[test]
+pub fn out_of_range_reads_truncate_in_stylus() {
+    let mut setup = Setup::new();
+
+    for case in CASES {
+        let (success, data) = case.expected();
+        let length = data.len() as u64;
+
+        for (offset, size) in out_of_range_reads(length) {
+            let start = offset.min(length) as usize;
+            let end = (offset + size).min(length) as usize;
+            let expected = Observed {
+                success,
+                length,
+                copied: data[start..end].to_vec().into(),
+            };
+
+            let observed = setup.observe(Vm::Stylus, case, offset, size);
+            assert_eq!(
+                observed,
+                Some(expected),
+                "{:?} reading {} bytes from {}",
+                case,
+                size,
+                offset
+            );
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use std::convert::Infallible;
+
+use alloy_signer_local::PrivateKeySigner;
+use alloy_sol_types::{sol, SolCall};
+use common::fixtures::{base_state, Fixtures};
+use common::signing::{
+    decode_tx_env, sign_authorization, test_key, transact_raw, SignedTx, SignedTxType,
+    TEST_CHAIN_ID,
+};
+use common::wasm_contract_init_code;
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{
+    keccak256, AccountInfo, Bytecode, EVMError, ExecutionResult, HaltReason, InvalidTransaction,
+    TxKind, U256,
+};
+
+mod common;
+
+const TEST_PROGRAM_BYTECODE: &[u8] = include_bytes!("assets/test_program.wasm");
+
+sol!("tests/assets/abi/test_program.sol");
+
+struct Setup {
+    db: CacheDB<EmptyDB>,
+    fixtures: Fixtures,
+    sender: PrivateKeySigner,
+}
+
+impl Setup {
+    /// The base state with `test_key(0)` holding `balance`.
+    fn new(balance: U256) -> Self {
+        let (mut db, fixtures) = base_state();
+        let sender = test_key(0);
+
+        let mut info = AccountInfo::default();
+        info.balance = balance;
+        db.insert_account_info(sender.address(), info);
+
+        Self { db, fixtures, sender }
+    }
+
+    fn send(&mut self, tx: &SignedTx) -> Result<ExecutionResult, EVMError<Infallible>> {
+        transact_raw(&mut self.db, &tx.encode(&self.sender))
+    }
+
+    fn nonce(&mut self) -> u64 {
+        self.db.load_account(self.sender.address()).unwrap().info.nonce
+    }
+}
+
+/// Enough for every transaction here, even at the full cost of its gas limit.
+const FUNDS: f64 = 1e20;
+
+fn set_storage(value: &str) -> Vec<u8> {
+    ITestProgram::setStorageCall {
+        key: keccak256("signed-slot"),
+        value: keccak256(value),
+    }
+    .abi_encode()
+}
+
+/// Intrinsic gas of a transaction carrying `data`, without access or authorization lists.
+fn intrinsic_gas(data: &[u8], create: bool) -> u64 {
+    let zeros = data.iter().filter(|byte| **byte == 0).count() as u64;
+    let non_zeros = data.len() as u64 - zeros;
+    let mut gas = 21_000 + 4 * zeros + 16 * non_zeros;
+
+    if create {
+        gas += 32_000 + 2 * (data.len() as u64).div_ceil(32);
+    }
+    gas
+}
+
+#[test]
+pub fn every_tx_type_decodes_and_executes() {
+    let mut setup = Setup::new(U256::from(FUNDS));
+    let program = setup.fixtures.test_program;
+    let authority = test_key(1);
+
+    let txs = [
+        SignedTx::legacy(TxKind::Call(program)),
+        SignedTx::eip2930(TxKind::Call(program)),
+        SignedTx::eip1559(TxKind::Call(program)).max_priority_fee_per_gas(1),
+        SignedTx::eip7702(program).authorization(sign_authorization(
+            &authority,
+            setup.fixtures.hostio_probe_evm,
+            0,
+        )),
+    ];
+
+    for (nonce, tx) in txs.into_iter().enumerate() {
+        let value = format!("{:?}", tx.tx_type);
+        let tx = tx.nonce(nonce as u64).data(set_storage(&value));
+
+        let env = decode_tx_env(&tx.encode(&setup.sender));
+        assert_eq!(env.caller, setup.sender.address(), "{:?}", tx.tx_type);
+        assert_eq!(env.nonce, Some(nonce as u64), "{:?}", tx.tx_type);
+        assert_eq!(env.chain_id, Some(TEST_CHAIN_ID), "{:?}", tx.tx_type);
+        assert_eq!(env.data, tx.data, "{:?}", tx.tx_type);
+        assert_eq!(
+            env.gas_priority_fee.is_some(),
+            matches!(tx.tx_type, SignedTxType::Eip1559 | SignedTxType::Eip7702),
+            "{:?}",
+            tx.tx_type
+        );
+
+        let result = setup.send(&tx).unwrap();
+        assert!(result.is_success(), "{:?}: {:?}", tx.tx_type, result);
+
+        let stored = setup.db.load_account(program).unwrap().storage[&U256::from_be_bytes(keccak256("signed-slot").0)];
+        assert_eq!(stored, U256::from_be_bytes(keccak256(&value).0), "{:?}", tx.tx_type);
+    }
+
+    assert_eq!(setup.nonce(), 4);
+
+    // The EIP-7702 transaction delegated the authority's account, signed separately from
+    // the transaction itself.
+    let delegated = setup.db.load_account(authority.address()).unwrap();
+    assert_eq!(
+        delegated.info.code,
+        Some(Bytecode::new_eip7702(setup.fixtures.hostio_probe_evm))
+    );
+}
+
+#[test]
+pub fn nonce_must_match_sender() {
+    let mut setup = Setup::new(U256::from(FUNDS));
+    let tx = SignedTx::eip1559(TxKind::Call(setup.fixtures.test_program))
+        .data(set_storage("nonce"));
+
+    match setup.send(&tx.clone().nonce(1)) {
+        Err(EVMError::Transaction(InvalidTransaction::NonceTooHigh { tx: 1, state: 0 })) => {}
+        result => panic!("Expected NonceTooHigh: {:?}", result),
+    }
+    assert_eq!(setup.nonce(), 0);
+
+    assert!(setup.send(&tx).unwrap().is_success());
+    assert_eq!(setup.nonce(), 1);
+
+    // Replaying the same signed transaction is rejected.
+    match setup.send(&tx) {
+        Err(EVMError::Transaction(InvalidTransaction::NonceTooLow { tx: 0, state: 1 })) => {}
+        result => panic!("Expected NonceTooLow: {:?}", result),
+    }
+}
+
+#[test]
+pub fn chain_id_must_match() {
+    let mut setup = Setup::new(U256::from(FUNDS));
+    let program = setup.fixtures.test_program;
+
+    for tx in [
+        SignedTx::legacy(TxKind::Call(program)).chain_id(1),
+        SignedTx::eip1559(TxKind::Call(program)).chain_id(TEST_CHAIN_ID + 1),
+    ] {
+        match setup.send(&tx.data(set_storage("chain"))) {
+            Err(EVMError::Transaction(InvalidTransaction::InvalidChainId)) => {}
+            result => panic!("Expected InvalidChainId: {:?}", result),
+        }
+    }
+
+    // Pre-EIP-155 transactions carry no chain ID, so there is nothing to check.
+    let tx = SignedTx::legacy(TxKind::Call(program))
+        .without_chain_id()
+        .data(set_storage("chain"));
+    assert_eq!(decode_tx_env(&tx.encode(&setup.sender)).chain_id, None);
+    assert!(setup.send(&tx).unwrap().is_success());
+}
+
+#[test]
+pub fn stylus_deploy_pays_intrinsic_gas() {
+    let mut setup = Setup::new(U256::from(FUNDS));
+    let init_code = wasm_contract_init_code(TEST_PROGRAM_BYTECODE.to_vec());
+    let intrinsic = intrinsic_gas(&init_code, true);
+    let tx = SignedTx::eip1559(TxKind::Create).data(init_code);
+
+    match setup.send(&tx.clone().gas_limit(intrinsic - 1)) {
+        Err(EVMError::Transaction(InvalidTransaction::CallGasCostMoreThanGasLimit)) => {}
+        result => panic!("Expected CallGasCostMoreThanGasLimit: {:?}", result),
+    }
+    assert_eq!(setup.nonce(), 0);
+
+    // Exactly the intrinsic gas is valid, but leaves nothing to run the init code.
+    match setup.send(&tx.clone().gas_limit(intrinsic)) {
+        Ok(ExecutionResult::Halt {
+            reason: HaltReason::OutOfGas(_),
+            gas_used,
+        }) => assert_eq!(gas_used, intrinsic),
+        result => panic!("Expected out of gas: {:?}", result),
+    }
+
+    let result = setup.send(&tx.nonce(1).gas_limit(1_000_000_000)).unwrap();
+    assert!(result.is_success(), "{:?}", result);
+    assert!(result.gas_used() > intrinsic);
+
+    let deployed = setup.sender.address().create(1);
+    assert!(setup.db.load_account(deployed).unwrap().info.code.is_some());
+}
+
+#[test]
+pub fn sender_must_afford_max_fee() {
+    let tx = SignedTx::legacy(TxKind::Call(test_key(2).address()))
+        .value(U256::from(1_000))
+        .gas_limit(21_000);
+    let max_cost = tx.max_cost();
+
+    let mut setup = Setup::new(max_cost - U256::from(1));
+    match setup.send(&tx) {
+        Err(EVMError::Transaction(InvalidTransaction::LackOfFundForMaxFee { .. })) => {}
+        result => panic!("Expected LackOfFundForMaxFee: {:?}", result),
+    }
+    assert_eq!(setup.nonce(), 0);
+
+    // A plain transfer uses all of its gas, so affording the maximum leaves nothing over.
+    let mut setup = Setup::new(max_cost);
+    let result = setup.send(&tx).unwrap();
+    assert!(result.is_success(), "{:?}", result);
+    assert_eq!(result.gas_used(), 21_000);
+
+    let sender = setup.db.load_account(setup.sender.address()).unwrap();
+    assert_eq!(sender.info.balance, U256::ZERO);
+    let recipient = setup.db.load_account(test_key(2).address()).unwrap();
+    assert_eq!(recipient.info.balance, U256::from(1_000));
+}
@@ -0,0 +1,181 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+use alloy_sol_types::{sol, SolCall};
+use common::fixtures::base_state;
+use common::multicall::Multicaller;
+use common::state_diff::{diff_transaction, Change, StateDiff};
+use common::state_dump::{DumpAccount, StateDump};
+use common::{evm_contract_init_code, DEPLOYER, EVM_CHILD_RUNTIME};
+use revm::db::CacheDB;
+use revm::primitives::{address, hex, keccak256, Address, ExecutionResult, TxEnv, TxKind, U256};
+use revm::{DatabaseCommit, DatabaseRef};
+
+mod common;
+
+/// Adds one to its slot 0.
+const COUNTER_RUNTIME: [u8; 10] = hex!("60005460010160005500");
+
+sol!("tests/assets/abi/test_program.sol");
+sol!("tests/assets/abi/create_program.sol");
+
+/// Runs and commits the transaction, returning its result and everything it changed.
+fn transact_diff<T: DatabaseRef>(
+    db: &mut CacheDB<T>,
+    to: Address,
+    data: Vec<u8>,
+) -> (ExecutionResult, StateDiff)
+where
+    T::Error: Debug,
+{
+    let evm = revm::Evm::builder()
+        .with_db(&mut *db)
+        .modify_tx_env(|tx: &mut TxEnv| {
+            tx.caller = DEPLOYER;
+            tx.transact_to = TxKind::Call(to);
+            tx.data = data.into();
+            tx.gas_limit = 1_000_000_000;
+        });
+    let outcome = evm.build().transact().unwrap();
+
+    let diff = diff_transaction(&*db, &outcome.state);
+    db.commit(outcome.state);
+    (outcome.result, diff)
+}
+
+fn set_storage_through_multicall(storage: Address) -> Vec<u8> {
+    Multicaller::multicallCall {
+        calls: vec![Multicaller::Call {
+            callType: Multicaller::CallType::CALL,
+            target: storage,
+            data: ITestProgram::setStorageCall {
+                key: keccak256("golden-slot"),
+                value: keccak256("golden-value"),
+            }
+            .abi_encode()
+            .into(),
+            value: U256::ZERO,
+            gas_limit: U256::ZERO,
+        }],
+    }
+    .abi_encode()
+}
+
+#[test]
+pub fn multicall_storage_write_diff() {
+    let (mut db, fixtures) = base_state();
+
+    let calldata = set_storage_through_multicall(fixtures.test_program);
+    let (result, diff) = transact_diff(&mut db, fixtures.multicall, calldata);
+    assert!(result.is_success(), "{:?}", result);
+
+    diff.assert_golden("multicall_storage_write");
+}
+
+#[test]
+pub fn create_with_endowment_diff() {
+    let (mut db, fixtures) = base_state();
+
+    let factory = fixtures.create_program;
+    db.load_account(factory).unwrap().info.balance = U256::from(1e18);
+
+    let calldata = ICreateProgram::createCall {
+        init_code: evm_contract_init_code(EVM_CHILD_RUNTIME.to_vec()).into(),
+        endowment: U256::from(0.25e18),
+    };
+    let (result, diff) = transact_diff(&mut db, factory, calldata.abi_encode());
+    assert!(result.is_success(), "{:?}", result);
+
+    diff.assert_golden("create_with_endowment");
+}
+
+#[test]
+pub fn reverted_call_only_spends_nonce() {
+    let (mut db, fixtures) = base_state();
+
+    let factory = fixtures.create_program;
+    let nonce = db.load_account(DEPLOYER).unwrap().info.nonce;
+
+    // The endowment is more than the factory has, so the whole call reverts.
+    let calldata = ICreateProgram::createCall {
+        init_code: evm_contract_init_code(EVM_CHILD_RUNTIME.to_vec()).into(),
+        endowment: U256::from(1),
+    };
+    let (result, diff) = transact_diff(&mut db, factory, calldata.abi_encode());
+    assert!(matches!(result, ExecutionResult::Revert { .. }), "{:?}", result);
+
+    assert_eq!(diff.accounts.len(), 1, "{}", diff.to_json());
+    let sender = &diff.accounts[&DEPLOYER];
+    assert_eq!(
+        sender.nonce,
+        Some(Change {
+            from: nonce,
+            to: nonce + 1
+        })
+    );
+    assert!(sender.balance.is_none() && sender.code.is_none() && sender.storage.is_empty());
+}
+
+#[test]
+pub fn reads_leave_state_untouched() {
+    let (mut db, fixtures) = base_state();
+    let nonce = db.load_account(DEPLOYER).unwrap().info.nonce;
+
+    let calldata = ITestProgram::getStorageCall { key: keccak256("golden-slot") };
+    let (result, mut diff) = transact_diff(&mut db, fixtures.test_program, calldata.abi_encode());
+    assert!(result.is_success(), "{:?}", result);
+
+    // The program was loaded, but only the sender changed.
+    let sender = diff.accounts.remove(&DEPLOYER).unwrap();
+    assert_eq!(sender.nonce, Some(Change { from: nonce, to: nonce + 1 }));
+    assert_eq!(diff, StateDiff::default());
+    assert_eq!(diff.to_json(), "{}");
+}
+
+#[test]
+pub fn diff_reads_prior_values_from_backing_dump() {
+    let counter = address!("00000000000000000000000000000000000c0de3");
+    let dump = StateDump::from_accounts(BTreeMap::from([
+        (DEPLOYER, DumpAccount { balance: U256::from(1e18), nonce: 3, ..Default::default() }),
+        (
+            counter,
+            DumpAccount {
+                code: COUNTER_RUNTIME.into(),
+                storage: BTreeMap::from([(U256::ZERO, U256::from(42))]),
+                ..Default::default()
+            },
+        ),
+    ]));
+
+    // Nothing is loaded yet, so the sender, the counter and its slot are all first read
+    // from the dump during the transaction.
+    let mut db = CacheDB::new(dump);
+    let (result, diff) = transact_diff(&mut db, counter, vec![]);
+    assert!(result.is_success(), "{:?}", result);
+
+    assert_eq!(diff.accounts.len(), 2, "{}", diff.to_json());
+
+    let sender = &diff.accounts[&DEPLOYER];
+    assert!(!sender.created);
+    assert_eq!(sender.nonce, Some(Change { from: 3, to: 4 }));
+    assert!(sender.balance.is_none() && sender.code.is_none() && sender.storage.is_empty());
+
+    let counter = &diff.accounts[&counter];
+    assert!(!counter.created);
+    assert!(counter.balance.is_none() && counter.nonce.is_none() && counter.code.is_none());
+    assert_eq!(
+        counter.storage,
+        BTreeMap::from([(U256::ZERO, Change { from: U256::from(42), to: U256::from(43) })])
+    );
+}
+
+#[test]
+pub fn state_diff_round_trips_through_json() {
+    let (mut db, fixtures) = base_state();
+
+    let calldata = set_storage_through_multicall(fixtures.test_program);
+    let (_, diff) = transact_diff(&mut db, fixtures.multicall, calldata);
+
+    let parsed: StateDiff = serde_json::from_str(&diff.to_json()).unwrap();
+    assert_eq!(parsed, diff);
+}
@@ -0,0 +1,202 @@
+use alloy_sol_types::{sol, SolCall};
+use common::arbitrum::{
+    read_program, write_program, ArbRetryableTx, ArbitrumTx, ProgramInfo, ARBOS_STATE_ADDRESS,
+    ARB_RETRYABLE_TX,
+};
+use common::state_dump::{export, export_json, StateDump};
+use common::{deploy_wasm, next_create_address, setup_simple_test, DEPLOYER};
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{address, keccak256, Address, Bytes, ExecutionResult, TxEnv, TxKind, U256};
+use revm::{DatabaseRef, STYLUS_MAGIC_BYTES};
+
+mod common;
+
+const TEST_PROGRAM_BYTECODE: &[u8] = include_bytes!("assets/test_program.wasm");
+
+const GENESIS_DUMP: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/state-dumps/genesis.json");
+const ANVIL_DUMP: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/state-dumps/anvil.json");
+const ARBOS_DUMP: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/state-dumps/arbos.json");
+
+/// Contract in both dumps that returns its slot 0, which holds 42.
+const SLOT_READER: Address = address!("00000000000000000000000000000000000c0de1");
+
+/// Activated program in the ArbOS dump that, like [`SLOT_READER`], returns its slot 0.
+const STYLUS_SLOT_READER: Address = address!("00000000000000000000000000000000000c0de2");
+const STYLUS_SLOT_READER_WAT: &str = include_str!("fixtures/state-dumps/slot-reader.wat");
+
+const L1_SENDER: Address = address!("5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a");
+
+sol!("tests/assets/abi/test_program.sol");
+
+fn transact<T: DatabaseRef>(db: &mut CacheDB<T>, to: Address, data: Vec<u8>) -> ExecutionResult
+where
+    T::Error: std::fmt::Debug,
+{
+    let evm = revm::Evm::builder()
+        .with_db(db)
+        .modify_tx_env(|tx: &mut TxEnv| {
+            tx.caller = DEPLOYER;
+            tx.transact_to = TxKind::Call(to);
+            tx.data = data.into();
+            tx.gas_limit = 1_000_000_000;
+        });
+
+    evm.build().transact_commit().unwrap()
+}
+
+#[test]
+pub fn genesis_and_anvil_dumps_agree() {
+    let genesis = StateDump::load(GENESIS_DUMP).unwrap();
+    let anvil = StateDump::load(ANVIL_DUMP).unwrap();
+
+    for address in [DEPLOYER, SLOT_READER] {
+        assert_eq!(genesis.basic_ref(address).unwrap(), anvil.basic_ref(address).unwrap());
+        assert_eq!(
+            genesis.storage_ref(address, U256::ZERO).unwrap(),
+            anvil.storage_ref(address, U256::ZERO).unwrap()
+        );
+    }
+
+    let deployer = genesis.basic_ref(DEPLOYER).unwrap().unwrap();
+    assert_eq!(deployer.nonce, 3);
+    assert_eq!(deployer.balance, U256::from(1e18));
+    assert_eq!(genesis.storage_ref(SLOT_READER, U256::ZERO).unwrap(), U256::from(42));
+    assert!(genesis.basic_ref(Address::ZERO).unwrap().is_none());
+}
+
+#[test]
+pub fn executes_against_dump() {
+    let mut db = CacheDB::new(StateDump::load(GENESIS_DUMP).unwrap());
+
+    let result = transact(&mut db, SLOT_READER, vec![]);
+    assert!(result.is_success(), "{:?}", result);
+    assert_eq!(result.output().unwrap().to_vec(), U256::from(42).to_be_bytes_vec());
+
+    // Programs deploy on top of the dump, picking up the deployer's nonce from it.
+    let expected = DEPLOYER.create(3);
+    assert_eq!(next_create_address(&db, DEPLOYER), expected);
+    assert_eq!(deploy_wasm(&mut db, TEST_PROGRAM_BYTECODE.to_vec(), DEPLOYER), expected);
+}
+
+/// The retryable in the ArbOS dump: a call to the Stylus slot reader, without call value or
+/// an auto-redeem.
+fn dump_ticket() -> ArbitrumTx {
+    ArbitrumTx::submit_retryable(L1_SENDER, STYLUS_SLOT_READER, Bytes::new()).beneficiary(DEPLOYER)
+}
+
+/// The ArbOS dump holds what the harness writes for [`dump_ticket`] and for activating the
+/// slot reader, so the slots it was generated with can't drift from the harness's layout.
+#[test]
+pub fn arbos_dump_matches_harness_layout() {
+    let mut dump = CacheDB::new(StateDump::load(ARBOS_DUMP).unwrap());
+
+    let program = dump.basic_ref(STYLUS_SLOT_READER).unwrap().unwrap();
+    let wasm = wat::parse_str(STYLUS_SLOT_READER_WAT).unwrap();
+    let code = program.code.unwrap().original_bytes();
+    assert_eq!(code.to_vec(), [STYLUS_MAGIC_BYTES.to_vec(), wasm].concat());
+    assert_eq!(
+        read_program(&mut dump, program.code_hash),
+        Some(ProgramInfo { version: 1, footprint: 1 })
+    );
+
+    let mut expected = CacheDB::new(EmptyDB::new());
+    dump_ticket().execute(&mut expected, U256::ZERO);
+    write_program(&mut expected, program.code_hash, ProgramInfo { version: 1, footprint: 1 });
+
+    let arbos = &export(&expected)[&ARBOS_STATE_ADDRESS];
+    assert_eq!(dump.basic_ref(ARBOS_STATE_ADDRESS).unwrap().unwrap().nonce, arbos.nonce);
+    for (slot, value) in &arbos.storage {
+        assert_eq!(dump.storage_ref(ARBOS_STATE_ADDRESS, *slot).unwrap(), *value, "{}", slot);
+    }
+}
+
+#[test]
+pub fn executes_against_arbos_dump() {
+    let mut db = CacheDB::new(StateDump::load(ARBOS_DUMP).unwrap());
+
+    let result = transact(&mut db, STYLUS_SLOT_READER, vec![]);
+    assert!(result.is_success(), "{:?}", result);
+    assert_eq!(result.output().unwrap().to_vec(), U256::from(42).to_be_bytes_vec());
+
+    // ArbRetryableTx reads the ticket out of the dump's ArbOS storage.
+    let ticket_id = dump_ticket().ticket_id();
+    let calldata = ArbRetryableTx::getBeneficiaryCall { ticketId: ticket_id };
+    let result = transact(&mut db, ARB_RETRYABLE_TX, calldata.abi_encode());
+    assert!(result.is_success(), "{:?}", result);
+    assert_eq!(
+        ArbRetryableTx::getBeneficiaryCall::abi_decode_returns(result.output().unwrap(), true)
+            .unwrap()
+            ._0,
+        DEPLOYER
+    );
+}
+
+/// The slot reader in the ArbOS dump only has an activation record. arbos-revm runs it off
+/// that record, so the first call costs the same as any later one.
+#[test]
+pub fn arbos_dump_program_runs_without_reactivation() {
+    let mut db = CacheDB::new(StateDump::load(ARBOS_DUMP).unwrap());
+    let code_hash = db.basic_ref(STYLUS_SLOT_READER).unwrap().unwrap().code_hash;
+
+    let first = transact(&mut db, STYLUS_SLOT_READER, vec![]);
+    assert!(first.is_success(), "{:?}", first);
+    let second = transact(&mut db, STYLUS_SLOT_READER, vec![]);
+    assert!(second.is_success(), "{:?}", second);
+
+    assert_eq!(first.gas_used(), second.gas_used());
+    assert_eq!(read_program(&mut db, code_hash), Some(ProgramInfo { version: 1, footprint: 1 }));
+}
+
+#[test]
+pub fn arbos_dump_program_needs_its_activation_record() {
+    let mut db = CacheDB::new(StateDump::load(ARBOS_DUMP).unwrap());
+    let code_hash = db.basic_ref(STYLUS_SLOT_READER).unwrap().unwrap().code_hash;
+    write_program(&mut db, code_hash, ProgramInfo { version: 0, footprint: 0 });
+
+    let result = transact(&mut db, STYLUS_SLOT_READER, vec![]);
+    assert!(!result.is_success(), "{:?}", result);
+    assert_eq!(read_program(&mut db, code_hash), None);
+}
+
+#[test]
+pub fn bad_dumps_are_errors() {
+    let missing = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/state-dumps/missing.json");
+    let err = StateDump::load(missing).unwrap_err();
+    assert!(err.starts_with(&format!("Failed to read {}: ", missing)), "{}", err);
+
+    let err = StateDump::from_json("{\"alloc\": ").unwrap_err();
+    assert!(err.starts_with("Invalid state dump: "), "{}", err);
+
+    let err = StateDump::from_json("{\"0xc0de\": {\"balance\": \"0x1\"}}").unwrap_err();
+    assert!(err.starts_with("Invalid state dump: "), "{}", err);
+}
+
+#[test]
+pub fn stylus_state_round_trips() {
+    let mut db = CacheDB::new(EmptyDB::new());
+    setup_simple_test(&mut db);
+
+    let program = deploy_wasm(&mut db, TEST_PROGRAM_BYTECODE.to_vec(), DEPLOYER);
+    let calldata = ITestProgram::setStorageCall {
+        key: keccak256("dump-slot"),
+        value: keccak256("dump-value"),
+    };
+    assert!(transact(&mut db, program, calldata.abi_encode()).is_success());
+
+    let json = export_json(&db);
+    let mut restored = CacheDB::new(StateDump::from_json(&json).unwrap());
+
+    // The restored program runs and sees the storage written before the dump.
+    let calldata = ITestProgram::getStorageCall {
+        key: keccak256("dump-slot"),
+    };
+    let result = transact(&mut restored, program, calldata.abi_encode());
+    assert!(result.is_success(), "{:?}", result);
+    assert_eq!(result.output().unwrap().to_vec(), keccak256("dump-value").to_vec());
+
+    // Exporting the restored state gives back what was dumped, less the read's nonce.
+    let (original, restored) = (export(&db), export(&restored));
+    assert_eq!(restored[&program], original[&program]);
+    assert_eq!(restored[&DEPLOYER].balance, original[&DEPLOYER].balance);
+    assert_eq!(restored[&DEPLOYER].nonce, original[&DEPLOYER].nonce + 1);
+}
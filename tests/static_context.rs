@@ -0,0 +1,266 @@
+use alloy_sol_types::{sol, SolCall};
+use arbos_revm_tests::trace::{CallTracer, Frame};
+use common::fixtures::{base_state, Fixtures};
+use common::multicall::{self, multicall, Multicaller};
+use common::{deploy_solidity, evm_contract_init_code, DEPLOYER};
+use revm::db::{CacheDB, EmptyDB};
+use revm::inspector_handle_register;
+use revm::interpreter::InstructionResult;
+use revm::primitives::{address, keccak256, Address, ExecutionResult, TxEnv, TxKind, B256, U256};
+
+mod common;
+
+sol!("tests/assets/abi/test_program.sol");
+sol!("tests/assets/abi/emit_log.sol");
+sol!("tests/assets/abi/create_program.sol");
+
+/// Receives the value-bearing call.
+const RECIPIENT: Address = address!("4242424242424242424242424242424242424242");
+
+/// EVM runtime that self-destructs to its caller: `CALLER SELFDESTRUCT`.
+const SELFDESTRUCT_RUNTIME: &[u8] = &[0x33, 0xff];
+
+/// Revert data of a program whose hostio tried to write in a static context: the name of
+/// arbos-revm's error. Both multicallers bubble it up unchanged.
+const WRITE_PROTECTION: &[u8] = b"WriteProtection";
+
+/// A state-modifying action taken by a Stylus program.
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    /// `storage_cache_bytes32`, flushed when the program returns.
+    StorageWrite,
+    /// `storage_cache_bytes32` followed by an explicit `storage_flush_cache`.
+    StorageFlush,
+    /// `transient_store_bytes32`.
+    TransientStore,
+    /// `emit_log`.
+    Log,
+    /// `create1`.
+    Create,
+    /// `create2`.
+    Create2,
+    /// `call_contract` with value, from multicall.wasm.
+    ValueCall,
+    /// multicall.wasm calling an EVM contract that runs `SELFDESTRUCT`.
+    SelfDestruct,
+}
+
+const ACTIONS: [Action; 8] = [
+    Action::StorageWrite,
+    Action::StorageFlush,
+    Action::TransientStore,
+    Action::Log,
+    Action::Create,
+    Action::Create2,
+    Action::ValueCall,
+    Action::SelfDestruct,
+];
+
+/// How a static caller sees the action fail.
+#[derive(Debug, PartialEq, Eq)]
+enum Rejection {
+    /// The hostio refused, reverting the program with a `WriteProtection` error.
+    WriteProtection,
+    /// The EVM callee halted with `StateChangeDuringStaticCall`, so the program's call
+    /// failed without return data and the program reverted with none.
+    FailedCall,
+}
+
+impl Action {
+    fn rejection(self) -> Rejection {
+        match self {
+            Action::SelfDestruct => Rejection::FailedCall,
+            _ => Rejection::WriteProtection,
+        }
+    }
+}
+
+fn call(call_type: Multicaller::CallType, target: Address, data: Vec<u8>, value: U256) -> Multicaller::Call {
+    Multicaller::Call {
+        value,
+        ..multicall::call(call_type, target, data)
+    }
+}
+
+struct Setup {
+    db: CacheDB<EmptyDB>,
+    fixtures: Fixtures,
+    selfdestruct: Address,
+}
+
+impl Setup {
+    fn new() -> Self {
+        let (mut db, fixtures) = base_state();
+        let selfdestruct = deploy_solidity(
+            &mut db,
+            evm_contract_init_code(SELFDESTRUCT_RUNTIME.to_vec()),
+            DEPLOYER,
+        );
+
+        // Funds the value-bearing call.
+        db.load_account(fixtures.multicall).unwrap().info.balance = U256::from(1e18);
+
+        Self {
+            db,
+            fixtures,
+            selfdestruct,
+        }
+    }
+
+    /// The Stylus program to call, and the calldata that makes it take `action`.
+    fn action(&self, action: Action) -> (Address, Vec<u8>) {
+        let key = keccak256("static-context-slot");
+        let value = keccak256("static-context-value");
+        let init_code = evm_contract_init_code(vec![0x00]);
+
+        match action {
+            Action::StorageWrite => (
+                self.fixtures.test_program,
+                ITestProgram::setStorageCall { key, value }.abi_encode(),
+            ),
+            Action::StorageFlush => (
+                self.fixtures.test_program,
+                ITestProgram::flushStorageCall { key, value }.abi_encode(),
+            ),
+            Action::TransientStore => (
+                self.fixtures.test_program,
+                ITestProgram::setTransientCall { key, value }.abi_encode(),
+            ),
+            Action::Log => (
+                self.fixtures.emit_log,
+                IEmitLog::emitLogCall {
+                    topics: vec![key],
+                    data: value.to_vec().into(),
+                }
+                .abi_encode(),
+            ),
+            Action::Create => (
+                self.fixtures.create_program,
+                ICreateProgram::createCall {
+                    init_code: init_code.into(),
+                    endowment: U256::ZERO,
+                }
+                .abi_encode(),
+            ),
+            Action::Create2 => (
+                self.fixtures.create_program,
+                ICreateProgram::create2Call {
+                    init_code: init_code.into(),
+                    salt: B256::repeat_byte(0x11),
+                    endowment: U256::ZERO,
+                }
+                .abi_encode(),
+            ),
+            Action::ValueCall => (
+                self.fixtures.multicall,
+                multicall(vec![call(
+                    Multicaller::CallType::CALL,
+                    RECIPIENT,
+                    vec![],
+                    U256::from(1),
+                )]),
+            ),
+            Action::SelfDestruct => (
+                self.fixtures.multicall,
+                multicall(vec![call(
+                    Multicaller::CallType::CALL,
+                    self.selfdestruct,
+                    vec![],
+                    U256::ZERO,
+                )]),
+            ),
+        }
+    }
+
+    /// Has `entry` call the program taking `action` with `call_type`, returning the result and
+    /// every frame it ran.
+    fn run(
+        &mut self,
+        entry: Address,
+        call_type: Multicaller::CallType,
+        action: Action,
+    ) -> (ExecutionResult, Vec<Frame>) {
+        let (target, data) = self.action(action);
+        let data = multicall(vec![call(call_type, target, data, U256::ZERO)]);
+
+        let mut evm = revm::Evm::builder()
+            .with_db(&mut self.db)
+            .with_external_context(CallTracer::default())
+            .modify_tx_env(|tx: &mut TxEnv| {
+                tx.caller = DEPLOYER;
+                tx.transact_to = TxKind::Call(entry);
+                tx.data = data.into();
+                tx.gas_limit = 1_000_000_000;
+            })
+            .append_handler_register(inspector_handle_register)
+            .build();
+
+        let result = evm.transact_commit().unwrap();
+        (result, std::mem::take(&mut evm.context.external.frames))
+    }
+
+    /// Where static contexts are entered from: the EVM multicall, then the Stylus one.
+    fn entries(&self) -> [Address; 2] {
+        [self.fixtures.multicall_evm, self.fixtures.multicall]
+    }
+}
+
+/// How the static call failed, given the transaction's result and frames.
+fn rejection(result: &ExecutionResult, frames: &[Frame], callee: Address) -> Option<Rejection> {
+    let ExecutionResult::Revert { output, .. } = result else {
+        return None;
+    };
+
+    if output.as_ref() == WRITE_PROTECTION {
+        return Some(Rejection::WriteProtection);
+    }
+
+    let callee_halt = frames
+        .iter()
+        .find(|frame| frame.to == Some(callee))
+        .map(|frame| frame.result);
+    (output.is_empty() && callee_halt == Some(InstructionResult::StateChangeDuringStaticCall))
+        .then_some(Rejection::FailedCall)
+}
+
+#[test]
+pub fn actions_succeed_outside_static_context() {
+    for entry in Setup::new().entries() {
+        for action in ACTIONS {
+            // Each action runs on its own state, so the creations can't collide.
+            let mut setup = Setup::new();
+            let (result, _) = setup.run(entry, Multicaller::CallType::CALL, action);
+            assert!(result.is_success(), "{:?} from {}: {:?}", action, entry, result);
+        }
+    }
+}
+
+#[test]
+pub fn actions_are_write_protected_in_static_context() {
+    let mut setup = Setup::new();
+
+    for entry in setup.entries() {
+        for action in ACTIONS {
+            let (result, frames) = setup.run(entry, Multicaller::CallType::STATICCALL, action);
+            assert_eq!(
+                rejection(&result, &frames, setup.selfdestruct),
+                Some(action.rejection()),
+                "{:?} from {}: {:?}",
+                action,
+                entry,
+                result
+            );
+            assert!(result.logs().is_empty());
+        }
+    }
+
+    // Nothing the rejected actions tried stuck.
+    let key = U256::from_be_bytes(keccak256("static-context-slot").0);
+    let Fixtures { test_program, create_program, .. } = setup.fixtures;
+    let db = &mut setup.db;
+    assert!(db.load_account(test_program).unwrap().storage.get(&key).is_none());
+    assert_eq!(db.load_account(create_program).unwrap().info.nonce, 1);
+    assert_eq!(db.load_account(RECIPIENT).unwrap().info.balance, U256::ZERO);
+    let code = &db.load_account(setup.selfdestruct).unwrap().info.code;
+    assert!(code.as_ref().is_some_and(|code| !code.is_empty()));
+}